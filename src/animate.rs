@@ -0,0 +1,199 @@
+//! Text animations of the simulations of days 14, 17, 23 and 24, for
+//! `aoc animate`.
+//!
+//! A day shows each step of its simulation as a [`Frame`], a grid of
+//! characters. The frames are cropped to a [`Viewport`], then written
+//! one after the other to a file, or drawn over each other in the
+//! terminal at a given frame rate.
+
+use crate::days::{day14, day17, day23, day24};
+use crate::grid::{Grid, Pos};
+use crate::params::{self, Param};
+use anyhow::{bail, Context};
+use std::io::Write;
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
+
+/// A step of a simulation.
+pub struct Frame<'a> {
+    pub caption: String,
+    pub grid: &'a Grid<char>,
+    /// What to follow when the viewport has no position, as the
+    /// falling rock of day 17.
+    pub focus: Option<Pos>,
+}
+impl<'a> Frame<'a> {
+    pub fn new(caption: impl Into<String>, grid: &'a Grid<char>) -> Self {
+        Self {
+            caption: caption.into(),
+            grid,
+            focus: None,
+        }
+    }
+    /// Set the position to follow.
+    pub fn focus(self, pos: Pos) -> Self {
+        Self {
+            focus: Some(pos),
+            ..self
+        }
+    }
+}
+
+/// What the days call on each frame, breaking to stop the simulation.
+pub type Show<'a> = dyn FnMut(Frame<'_>) -> ControlFlow<()> + 'a;
+
+/// The part of the frames to show, parsed from `ROWSxCOLS` or
+/// `ROWSxCOLS@ROW,COL`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Viewport {
+    /// The number of rows and columns, the whole frame if `None`.
+    pub size: Option<(usize, usize)>,
+    /// The position of the top left corner, following the focus of
+    /// the frames (or at their top left corner) if `None`.
+    pub at: Option<Pos>,
+}
+impl std::str::FromStr for Viewport {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let bad = || {
+            format!(
+                "bad viewport {:?}, expected ROWSxCOLS or ROWSxCOLS@ROW,COL",
+                s
+            )
+        };
+        let (size, at) = match s.split_once('@') {
+            Some((size, at)) => (size, Some(at)),
+            None => (s, None),
+        };
+        let (rows, cols) = size.split_once('x').with_context(bad)?;
+        let size = (
+            rows.parse().with_context(bad)?,
+            cols.parse().with_context(bad)?,
+        );
+        let at = match at.map(|at| at.split_once(',')) {
+            None => None,
+            Some(Some((row, col))) => Some((
+                row.parse().with_context(bad)?,
+                col.parse().with_context(bad)?,
+            )),
+            Some(None) => bail!(bad()),
+        };
+        Ok(Viewport {
+            size: Some(size),
+            at,
+        })
+    }
+}
+impl Viewport {
+    /// The lines of the frame in the viewport, the positions outside of
+    /// the grid being blank.
+    pub fn crop(&self, frame: &Frame) -> Vec<String> {
+        let grid = frame.grid;
+        let first = grid.pos((0, 0));
+        let (h, w) = self.size.unwrap_or((grid.height(), grid.width()));
+        let at = match (self.at, frame.focus) {
+            (Some(at), _) => at,
+            (None, Some(f)) if self.size.is_some() => (f.0 - h as i64 / 2, f.1 - w as i64 / 2),
+            (None, _) => first,
+        };
+        (at.0..at.0 + h as i64)
+            .map(|y| {
+                (at.1..at.1 + w as i64)
+                    .map(|x| grid.coord((y, x)).map_or(' ', |c| grid[c]))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// How to render the frames.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Options {
+    pub viewport: Viewport,
+    /// The frames per second when drawn over each other in a terminal,
+    /// `None` to write them one after the other.
+    pub fps: Option<f64>,
+    /// Stop after this number of frames.
+    pub max_frames: Option<usize>,
+    /// The parameters of the day, as the number of rocks of day 17.
+    pub params: Vec<Param>,
+}
+
+fn write_frame(
+    out: &mut dyn Write,
+    caption: &str,
+    lines: &[String],
+    terminal: bool,
+) -> std::io::Result<()> {
+    if terminal {
+        // home, then each line erasing the rest of the previous frame
+        writeln!(out, "\x1b[H{}\x1b[K", caption)?;
+        for l in lines {
+            writeln!(out, "{}\x1b[K", l)?;
+        }
+        write!(out, "\x1b[J")?;
+        out.flush()
+    } else {
+        writeln!(out, "{}", caption)?;
+        for l in lines {
+            writeln!(out, "{}", l)?;
+        }
+        writeln!(out)
+    }
+}
+
+/// Run the simulation of a part of a day, rendering its frames to `out`.
+/// Returns the number of frames.
+pub fn render(
+    day: u8,
+    part: u8,
+    input: &str,
+    options: &Options,
+    out: &mut dyn Write,
+) -> anyhow::Result<usize> {
+    if ![14, 17, 23, 24].contains(&day) {
+        bail!("day {} can't be animated, only days 14, 17, 23 and 24", day);
+    }
+    if !(1..=2).contains(&part) {
+        bail!("no part {}", part);
+    }
+    if options.fps.is_some() {
+        write!(out, "\x1b[2J")?;
+    }
+    let delay = options
+        .fps
+        .filter(|&fps| fps > 0.)
+        .map(|fps| Duration::from_secs_f64(1. / fps));
+    let mut nb = 0;
+    let mut error = None;
+    let mut next = Instant::now();
+    let mut show = |frame: Frame| {
+        if options.max_frames.is_some_and(|max| nb >= max) {
+            return ControlFlow::Break(());
+        }
+        nb += 1;
+        let lines = options.viewport.crop(&frame);
+        if let Err(e) = write_frame(out, &frame.caption, &lines, options.fps.is_some()) {
+            error = Some(e);
+            return ControlFlow::Break(());
+        }
+        if let Some(delay) = delay {
+            next += delay;
+            std::thread::sleep(next.saturating_duration_since(Instant::now()));
+        }
+        ControlFlow::Continue(())
+    };
+    let p = &options.params;
+    let _ = match day {
+        14 => day14::animate(&params::parse::<day14::Day14>(input, p)?, part, &mut show),
+        17 => day17::animate(&params::parse::<day17::Day17>(input, p)?, part, &mut show),
+        23 => day23::animate(&params::parse::<day23::Day23>(input, p)?, part, &mut show),
+        24 => day24::animate(&params::parse::<day24::Day24>(input, p)?, part, &mut show),
+        _ => unreachable!("checked above"),
+    };
+    if let Some(e) = error {
+        return Err(e.into());
+    }
+    out.flush()?;
+    Ok(nb)
+}
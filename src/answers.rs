@@ -0,0 +1,123 @@
+//! A store of the known answers, to check that they don't change.
+//!
+//! The answers are kept in a JSON file, keyed by day, part and input
+//! checksum (as given by [`report::checksum`](crate::report::checksum)):
+//!
+//! ```text
+//! [
+//!   {"day":1,"part":1,"input":"fnv1a64:658a17a9d4621e0a","answer":72478},
+//!   ...
+//! ]
+//! ```
+
+use crate::report::DayReport;
+use crate::solution::Answer;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The default answer store.
+pub const DEFAULT_STORE: &str = "answers.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: Answer,
+}
+
+/// The known answers.
+#[derive(Debug, Clone, Default)]
+pub struct Store {
+    answers: BTreeMap<(u8, u8, String), Answer>,
+}
+impl Store {
+    /// Load the store, empty if the file doesn't exist.
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        let json = match std::fs::read_to_string(path) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            json => json.with_context(|| format!("can't read {:?}", path))?,
+        };
+        let entries: Vec<Entry> =
+            serde_json::from_str(&json).with_context(|| format!("bad answer store {:?}", path))?;
+        let answers = entries
+            .into_iter()
+            .map(|e| ((e.day, e.part, e.input), e.answer))
+            .collect();
+        Ok(Self { answers })
+    }
+    /// Save the store, sorted by day, part and input, one answer per
+    /// line for readable diffs.
+    pub fn save(&self, path: &str) -> anyhow::Result<()> {
+        let mut lines = vec![];
+        for ((day, part, input), answer) in &self.answers {
+            let entry = Entry {
+                day: *day,
+                part: *part,
+                input: input.clone(),
+                answer: answer.clone(),
+            };
+            lines.push(format!("  {}", serde_json::to_string(&entry)?));
+        }
+        let json = format!("[\n{}\n]\n", lines.join(",\n"));
+        std::fs::write(path, json).with_context(|| format!("can't write {:?}", path))
+    }
+    /// The known answer of a part on an input.
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&Answer> {
+        self.answers.get(&(day, part, input.into()))
+    }
+    /// Remember the answers of a day.
+    pub fn record(&mut self, report: &DayReport) {
+        for p in &report.parts {
+            let key = (report.day, p.part, report.input_checksum.clone());
+            self.answers.insert(key, p.answer.clone());
+        }
+    }
+    /// The answers of a day that are not the known ones.
+    pub fn verify(&self, report: &DayReport) -> Vec<Mismatch> {
+        let mut mismatches = vec![];
+        for p in &report.parts {
+            let expected = self.get(report.day, p.part, &report.input_checksum);
+            if expected != Some(&p.answer) {
+                mismatches.push(Mismatch {
+                    day: report.day,
+                    part: p.part,
+                    input: report.input_checksum.clone(),
+                    expected: expected.cloned(),
+                    actual: p.answer.clone(),
+                });
+            }
+        }
+        mismatches
+    }
+}
+
+/// An answer that is not the known one, displayed as a diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    /// `None` if there is no known answer.
+    pub expected: Option<Answer>,
+    pub actual: Answer,
+}
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "day {} part {} on {}:", self.day, self.part, self.input)?;
+        match &self.expected {
+            None => writeln!(f, "- (no known answer)")?,
+            Some(a) => {
+                for l in a.to_string().lines() {
+                    writeln!(f, "- {}", l)?;
+                }
+            }
+        }
+        for l in self.actual.to_string().lines() {
+            writeln!(f, "+ {}", l)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+//! Timing of the parsing and of the two parts of the days.
+//!
+//! The results can be appended to a history file, one line per day and
+//! stage, tab separated:
+//!
+//! ```text
+//! timestamp  commit  day  stage  iterations  min_ns  median_ns  max_ns
+//! ```
+//!
+//! Committing this file makes the regressions visible in the diffs.
+
+use crate::params::Param;
+use crate::solution::{Solver, Streaming};
+use std::fmt;
+use std::io::Write;
+use std::time::{Duration, Instant, SystemTime};
+
+/// The timing statistics of a stage.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+impl Stats {
+    /// Compute the statistics of non empty samples.
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort_unstable();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>12.1?} {:>12.1?} {:>12.1?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+/// The timings of a day.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub day: u8,
+    pub iterations: usize,
+    /// Whether the parsing also solves the parts, see
+    /// [`Streaming::SinglePass`].
+    pub single_pass: bool,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+impl Bench {
+    /// The stages with their names, the parsing being named `solve`
+    /// when it solves the parts.
+    pub fn stages(&self) -> [(&'static str, Stats); 3] {
+        [
+            (if self.single_pass { "solve" } else { "parse" }, self.parse),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ]
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let now = Instant::now();
+    let res = f();
+    (res, now.elapsed())
+}
+
+/// Time the parsing and the two parts of a day with the given
+/// parameters, `iterations` times each.
+pub fn bench(
+    solver: &dyn Solver,
+    input: &str,
+    params: &[Param],
+    iterations: usize,
+) -> anyhow::Result<Bench> {
+    if iterations == 0 {
+        anyhow::bail!("at least one iteration is needed");
+    }
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+    for _ in 0..iterations {
+        let (parsed, d) = time(|| solver.parse_with(input, params));
+        let parsed = parsed?;
+        parse.push(d);
+        let (answer, d) = time(|| solver.part(&*parsed, 1));
+        answer?;
+        part1.push(d);
+        let (answer, d) = time(|| solver.part(&*parsed, 2));
+        answer?;
+        part2.push(d);
+    }
+    Ok(Bench {
+        day: solver.day(),
+        iterations,
+        single_pass: solver.streaming() == Streaming::SinglePass,
+        parse: Stats::new(parse),
+        part1: Stats::new(part1),
+        part2: Stats::new(part2),
+    })
+}
+
+/// Print a table of the results.
+pub fn print(benches: &[Bench]) {
+    println!(
+        "{:>3} {:<6} {:>12} {:>12} {:>12}",
+        "day", "stage", "min", "median", "max"
+    );
+    for b in benches {
+        for (stage, stats) in b.stages() {
+            println!("{:>3} {:<6} {}", b.day, stage, stats);
+        }
+    }
+}
+
+/// The current commit, if we are in a git repository.
+fn commit() -> Option<String> {
+    let out = std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    let commit = String::from_utf8(out.stdout).ok()?;
+    (out.status.success() && !commit.trim().is_empty()).then(|| commit.trim().into())
+}
+
+/// Append the results to the history file.
+pub fn append_history(path: &str, benches: &[Bench]) -> anyhow::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)?
+        .as_secs();
+    let commit = commit().unwrap_or_else(|| "-".into());
+    let mut f = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if f.metadata()?.len() == 0 {
+        writeln!(
+            f,
+            "# timestamp\tcommit\tday\tstage\titerations\tmin_ns\tmedian_ns\tmax_ns"
+        )?;
+    }
+    for b in benches {
+        for (stage, s) in b.stages() {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                timestamp,
+                commit,
+                b.day,
+                stage,
+                b.iterations,
+                s.min.as_nanos(),
+                s.median.as_nanos(),
+                s.max.as_nanos(),
+            )?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    aoc2022::cli::main()
+}
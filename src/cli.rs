@@ -0,0 +1,662 @@
+//! The command line front end.
+//!
+//! ```text
+//! aoc run [DAYS] [--input PATH|-] [--format text|json] [--record|--verify] [--answers PATH] [--threads N] [--trace|--trace-file PATH] [--param PARAM]...
+//! aoc bench [DAYS] [--input PATH|-] [--iterations N] [--history PATH|--no-history] [--threads N] [--param PARAM]...
+//! aoc generate [DAYS] [--size N] [--seed N] [--output PATH]
+//! aoc animate DAY [--input PATH|-] [--part N] [--fps N] [--viewport VIEWPORT] [--frames N] [--output PATH] [--param PARAM]...
+//! aoc image DAY [--input PATH|-] [--part N] [--scale N] [--output PATH]
+//! aoc check [DAYS] [--input PATH|-]
+//! aoc params [DAYS]
+//! aoc inventory [--input PATH|-] [--top N]
+//! aoc strategies [--input PATH|-] [--param PARAM]...
+//! ```
+//!
+//! `DAYS` can be a day (`16`), a range (`3..7` or `3-7`), a comma
+//! separated list of them (`1,3..5`) or `all` (the default). `PATH` can
+//! contain `{}`, replaced by the day number, and `-` reads the input
+//! from stdin. The output path of `generate`, `animate` and `image` is a
+//! template too, stdout being used without it. Without days, `check`
+//! guesses the day of an input path without `{}` (or of stdin). `PARAM`
+//! changes a constant of the puzzles, as `y=10` for day 15, or `15.y=10`
+//! to only change it for day 15 when running several days.
+
+use crate::animate::{self, Viewport};
+use crate::answers::{self, Store};
+use crate::bench;
+use crate::days::{self, day01, day02, NB_DAYS};
+use crate::generate;
+use crate::image;
+use crate::parallel;
+use crate::params::{self, Param};
+use crate::parse;
+use crate::report::{self, DayReport, Report};
+use crate::solution::{Answer, Streaming};
+use crate::trace;
+use anyhow::{bail, Context};
+use std::io::{BufRead, BufReader, BufWriter, IsTerminal, Read};
+use std::path::Path;
+
+/// The default input path template.
+pub const DEFAULT_INPUT: &str = "data/input{}.txt";
+
+/// The default benchmark history file.
+pub const DEFAULT_HISTORY: &str = "bench_history.tsv";
+
+const USAGE: &str = "\
+Usage: aoc run [DAYS] [OPTIONS]
+       aoc bench [DAYS] [OPTIONS]
+       aoc generate [DAYS] [OPTIONS]
+       aoc animate DAY [OPTIONS]
+       aoc image DAY [OPTIONS]
+       aoc check [DAYS] [OPTIONS]
+       aoc params [DAYS]
+       aoc inventory [OPTIONS]
+       aoc strategies [OPTIONS]
+
+Commands:
+    run       solve the days, printing the answers
+    bench     time the parsing and the two parts of the days
+    generate  write seeded random inputs of the days
+    animate   show the simulation of day 14, 17, 23 or 24, step by step
+    image     export a map of day 8, 12, 14, 18 or 23 as a PBM or PPM image
+    check     check that the inputs are valid for their days, or guess the
+              day of a single input given without DAYS
+    params    list the parameters of the days, with their default values
+    inventory print statistics of the calories carried by the elves of
+              day 1, and the elves carrying the most
+    strategies
+              score the strategy guide of day 2 with every reading of
+              our letters as moves, and as shifts of the other move
+
+DAYS is a day (16), a range (3..7 or 3-7), a comma separated list of
+them (1,3..5) or all (the default).
+
+Options:
+    -i, --input PATH      input file, `{}` is replaced by the day number,
+                          `-` for stdin [default: data/input{}.txt]
+    -f, --format FORMAT   `text` or `json` answers [default: text]
+    -n, --iterations N    number of benchmark iterations [default: 10]
+        --history PATH    benchmark history file to append to
+                          [default: bench_history.tsv]
+        --no-history      do not write the benchmark history
+        --record          remember the answers in the answer store
+        --verify          fail if the answers are not the remembered ones
+        --answers PATH    answer store [default: answers.json]
+    -j, --threads N       number of threads, 0 for one per core: the days
+                          are run in parallel (except for bench) and so
+                          are some loops inside days 15, 16 and 19, the
+                          answers being printed in order [default: 1]
+        --size N          size of the generated inputs, as the number
+                          of lines [default: close to the real inputs]
+        --seed N          seed of the generated inputs [default: 0]
+    -o, --output PATH     generated input, animation or image file, `{}`
+                          is replaced by the day number [default: stdout,
+                          animated]
+    -p, --part N          part to animate or export [default: 1]
+        --fps N           frames per second of the animation in the
+                          terminal, 0 for no delay [default: 10]
+        --viewport VIEWPORT
+                          part of the animation shown, as ROWSxCOLS,
+                          following what moves, or ROWSxCOLS@ROW,COL
+                          [default: all]
+        --frames N        stop the animation after N frames
+        --scale N         pixels per cell of the images [default: 1]
+        --top N           number of elves carrying the most calories to
+                          list in the inventory [default: 3]
+        --trace           log what the days do on stderr, as the crane
+                          moves of day 5 or the throws of day 11
+        --trace-file PATH log what the days do in a file
+    -P, --param PARAM     change a parameter of the days, as `y=10`, or
+                          `15.y=10` for day 15 only, to run the examples
+                          (see `aoc params`)
+    -h, --help            print this help";
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    /// A path template, `{}` being replaced by the day number.
+    Path(String),
+}
+impl Default for Source {
+    fn default() -> Self {
+        Source::Path(DEFAULT_INPUT.into())
+    }
+}
+impl std::str::FromStr for Source {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "" => bail!("empty input path"),
+            "-" => Ok(Source::Stdin),
+            _ => Ok(Source::Path(s.into())),
+        }
+    }
+}
+impl Source {
+    /// The path of the input of the given day, `None` for stdin.
+    pub fn path(&self, day: u8) -> Option<String> {
+        match self {
+            Source::Stdin => None,
+            Source::Path(p) => Some(p.replace("{}", &day.to_string())),
+        }
+    }
+    /// Read the whole input of the given day.
+    pub fn read(&self, day: u8) -> anyhow::Result<String> {
+        let mut input = String::new();
+        match self.path(day) {
+            None => {
+                std::io::stdin().read_to_string(&mut input)?;
+            }
+            Some(path) => {
+                std::fs::File::open(&path)
+                    .and_then(|mut f| f.read_to_string(&mut input))
+                    .with_context(|| format!("can't read {:?}", path))?;
+            }
+        }
+        Ok(input)
+    }
+    /// A reader on the input of the given day, to read it line by line.
+    pub fn reader(&self, day: u8) -> anyhow::Result<Box<dyn BufRead>> {
+        match self.path(day) {
+            None => Ok(Box::new(std::io::stdin().lock())),
+            Some(path) => {
+                let f =
+                    std::fs::File::open(&path).with_context(|| format!("can't read {:?}", path))?;
+                Ok(Box::new(BufReader::new(f)))
+            }
+        }
+    }
+    /// Locate the parse errors of the given day in the input file.
+    fn locate(&self, day: u8, e: anyhow::Error) -> anyhow::Error {
+        match self.path(day) {
+            Some(path) => parse::with_path(e, &path),
+            None => e,
+        }
+    }
+}
+
+/// Parse a day selection as `16`, `3..7`, `3-7`, `1,3..5` or `all`.
+pub fn parse_days(s: &str) -> anyhow::Result<Vec<u8>> {
+    let day = |s: &str| -> anyhow::Result<u8> {
+        let d = s
+            .trim()
+            .parse::<u8>()
+            .with_context(|| format!("bad day {:?}", s))?;
+        if !(1..=NB_DAYS).contains(&d) {
+            bail!("day {} is not in 1..={}", d, NB_DAYS);
+        }
+        Ok(d)
+    };
+    if s == "all" {
+        return Ok((1..=NB_DAYS).collect());
+    }
+    let mut res = vec![];
+    for part in s.split(',') {
+        match part.split_once("..").or_else(|| part.split_once('-')) {
+            Some((b, e)) => {
+                let (b, e) = (day(b)?, day(e.trim_start_matches('='))?);
+                if b > e {
+                    bail!("empty range {:?}", part);
+                }
+                res.extend(b..=e);
+            }
+            None => res.push(day(part)?),
+        }
+    }
+    Ok(res)
+}
+
+/// How to print the answers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Format {
+    /// `Part1: X` lines.
+    #[default]
+    Text,
+    /// A JSON [`report`], with the timings and the input checksums.
+    Json,
+}
+impl std::str::FromStr for Format {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("unknown format {:?}, expected text or json", s),
+        }
+    }
+}
+
+/// The parsed command line.
+#[derive(Debug)]
+struct Args {
+    days: Option<Vec<u8>>,
+    source: Source,
+    format: Format,
+    iterations: usize,
+    history: Option<String>,
+    record: bool,
+    verify: bool,
+    answers: String,
+    size: Option<usize>,
+    seed: u64,
+    output: Option<String>,
+    threads: usize,
+    /// Where to log the trace, `-` for stderr.
+    trace: Option<String>,
+    part: u8,
+    fps: f64,
+    viewport: Viewport,
+    frames: Option<usize>,
+    scale: usize,
+    top: usize,
+    params: Vec<Param>,
+}
+impl Default for Args {
+    fn default() -> Self {
+        Self {
+            days: None,
+            source: Source::default(),
+            format: Format::default(),
+            iterations: 10,
+            history: Some(DEFAULT_HISTORY.into()),
+            record: false,
+            verify: false,
+            answers: answers::DEFAULT_STORE.into(),
+            size: None,
+            seed: 0,
+            output: None,
+            threads: 1,
+            trace: None,
+            part: 1,
+            fps: 10.,
+            viewport: Viewport::default(),
+            frames: None,
+            scale: 1,
+            top: 3,
+            params: vec![],
+        }
+    }
+}
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> anyhow::Result<Self> {
+        let mut res = Args::default();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .with_context(|| format!("missing value for {}", name))
+            };
+            match arg.as_str() {
+                "-i" | "--input" => res.source = value(&arg)?.parse()?,
+                "-f" | "--format" => res.format = value(&arg)?.parse()?,
+                "-n" | "--iterations" => res.iterations = value(&arg)?.parse()?,
+                "--history" => res.history = Some(value(&arg)?),
+                "--no-history" => res.history = None,
+                "--record" => res.record = true,
+                "--verify" => res.verify = true,
+                "--answers" => res.answers = value(&arg)?,
+                "--size" => res.size = Some(value(&arg)?.parse()?),
+                "--seed" => res.seed = value(&arg)?.parse()?,
+                "-o" | "--output" => res.output = Some(value(&arg)?),
+                "-j" | "--threads" => res.threads = value(&arg)?.parse()?,
+                "--trace" => res.trace = Some("-".into()),
+                "--trace-file" => res.trace = Some(value(&arg)?),
+                "-p" | "--part" => res.part = value(&arg)?.parse()?,
+                "--fps" => res.fps = value(&arg)?.parse()?,
+                "--viewport" => res.viewport = value(&arg)?.parse()?,
+                "--frames" => res.frames = Some(value(&arg)?.parse()?),
+                "--scale" => res.scale = value(&arg)?.parse()?,
+                "--top" => res.top = value(&arg)?.parse()?,
+                "-P" | "--param" => res.params.push(value(&arg)?.parse()?),
+                _ if arg.starts_with('-') => bail!("unknown option {:?}", arg),
+                _ if res.days.is_none() => res.days = Some(parse_days(&arg)?),
+                _ => bail!("unexpected argument {:?}", arg),
+            }
+        }
+        if res.record && res.verify {
+            bail!("--record and --verify can't be used together");
+        }
+        if (res.record || res.verify) && !res.params.is_empty() {
+            bail!("the answers with changed parameters can't be recorded or verified");
+        }
+        Ok(res)
+    }
+    /// Check that the days of the parameters have them.
+    fn check_params(&self) -> anyhow::Result<()> {
+        let days = self.days.clone().unwrap_or_else(|| (1..=NB_DAYS).collect());
+        for p in &self.params {
+            for day in p.day.map_or(days.clone(), |d| vec![d]) {
+                let known = days::get(day)?.params();
+                if known.iter().all(|(k, _)| *k != p.key) {
+                    let names: Vec<_> = known.iter().map(|(k, _)| *k).collect();
+                    match names.is_empty() {
+                        true => bail!("day {} has no parameters, not even {:?}", day, p.key),
+                        false => bail!(
+                            "day {} has no parameter {:?}, only {}",
+                            day,
+                            p.key,
+                            names.join(", ")
+                        ),
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+    fn days(&self) -> anyhow::Result<Vec<u8>> {
+        let days = self.days.clone().unwrap_or_else(|| (1..=NB_DAYS).collect());
+        if self.source == Source::Stdin && days.len() > 1 {
+            bail!("stdin can only be used for a single day");
+        }
+        Ok(days)
+    }
+}
+
+fn print_answers<'a>(answers: impl Iterator<Item = &'a Answer>) {
+    for (part, answer) in (1..).zip(answers) {
+        match answer {
+            Answer::None => (),
+            Answer::Text(s) if s.contains('\n') => println!("Part{}:\n{}", part, s.trim_end()),
+            _ => println!("Part{}: {}", part, answer),
+        }
+    }
+}
+
+fn solve(args: &Args, day: u8) -> anyhow::Result<DayReport> {
+    let solver = days::get(day)?;
+    // the streaming days never have their whole input in memory
+    let report = match solver.streaming() {
+        Streaming::No => report::solve(solver, &args.source.read(day)?, &args.params),
+        _ => report::solve_reader(solver, args.source.reader(day)?, &args.params),
+    };
+    report
+        .map_err(|e| args.source.locate(day, e))
+        .with_context(|| format!("day {} failed", day))
+}
+
+fn run(args: &Args) -> anyhow::Result<()> {
+    let output: Box<dyn std::io::Write + Send> = match args.trace.as_deref() {
+        None => return run_days(args),
+        Some("-") => Box::new(BufWriter::new(std::io::stderr())),
+        Some(path) => Box::new(BufWriter::new(
+            std::fs::File::create(path).with_context(|| format!("can't write {:?}", path))?,
+        )),
+    };
+    trace::set_output(Some(output));
+    let res = run_days(args);
+    // flushed even on errors, when the trace is the most useful
+    trace::set_output(None);
+    res
+}
+
+fn run_days(args: &Args) -> anyhow::Result<()> {
+    parallel::set_threads(args.threads);
+    let days = args.days()?;
+    let mut store = match args.record || args.verify {
+        true => Some(Store::load(&args.answers)?),
+        false => None,
+    };
+    let mut mismatches = vec![];
+    let mut reports = vec![];
+    // solved one after the other while printed, or all at once in parallel
+    let solved: Box<dyn Iterator<Item = _>> = match parallel::threads() {
+        1 => Box::new(days.iter().map(|&day| solve(args, day))),
+        _ => Box::new(parallel::map(&days, |&day| solve(args, day)).into_iter()),
+    };
+    for (&day, report) in days.iter().zip(solved) {
+        if days.len() > 1 && args.format == Format::Text {
+            println!("Day {}:", day);
+        }
+        let report = report?;
+        if args.format == Format::Text {
+            print_answers(report.answers());
+        }
+        match &mut store {
+            Some(store) if args.record => store.record(&report),
+            Some(store) => mismatches.extend(store.verify(&report)),
+            None => (),
+        }
+        reports.push(report);
+    }
+    if args.format == Format::Json {
+        // the same shape whatever the number of days
+        let json = serde_json::to_string_pretty(&Report::new(reports))?;
+        println!("{}", json);
+    }
+    if let Some(store) = store.filter(|_| args.record) {
+        store.save(&args.answers)?;
+    }
+    if !mismatches.is_empty() {
+        for m in &mismatches {
+            eprint!("{}", m);
+        }
+        bail!("{} answers are not the known ones", mismatches.len());
+    }
+    Ok(())
+}
+
+fn run_bench(args: &Args) -> anyhow::Result<()> {
+    if args.format != Format::Text {
+        bail!("the benchmarks are only printed as text");
+    }
+    if args.record || args.verify {
+        bail!("the benchmarks don't check the answers");
+    }
+    if args.trace.is_some() {
+        bail!("the benchmarks can't be traced");
+    }
+    parallel::set_threads(args.threads);
+    let mut benches = vec![];
+    for day in args.days()? {
+        let input = args.source.read(day)?;
+        let b = bench::bench(days::get(day)?, &input, &args.params, args.iterations)
+            .map_err(|e| args.source.locate(day, e))
+            .with_context(|| format!("day {} failed", day))?;
+        benches.push(b);
+    }
+    bench::print(&benches);
+    if let Some(path) = &args.history {
+        bench::append_history(path, &benches)
+            .with_context(|| format!("can't write history to {:?}", path))?;
+    }
+    Ok(())
+}
+
+fn run_generate(args: &Args) -> anyhow::Result<()> {
+    let days = args.days.clone().unwrap_or_else(|| (1..=NB_DAYS).collect());
+    if days.len() > 1 && !args.output.as_ref().is_some_and(|p| p.contains("{}")) {
+        bail!("several days need an output path containing `{{}}`");
+    }
+    for day in days {
+        let size = args
+            .size
+            .unwrap_or(generate::DEFAULT_SIZES[day as usize - 1]);
+        let input = generate::generate(day, size, args.seed)
+            .with_context(|| format!("can't generate day {}", day))?;
+        match &args.output {
+            None => print!("{}", input),
+            Some(p) => {
+                let path = p.replace("{}", &day.to_string());
+                std::fs::write(&path, input).with_context(|| format!("can't write {:?}", path))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_animate(args: &Args) -> anyhow::Result<()> {
+    let Some(&[day]) = args.days.as_deref() else {
+        bail!("a single day to animate is expected");
+    };
+    let input = args.source.read(day)?;
+    let mut options = animate::Options {
+        viewport: args.viewport,
+        fps: Some(args.fps),
+        max_frames: args.frames,
+        params: args.params.clone(),
+    };
+    let res = match &args.output {
+        None => animate::render(
+            day,
+            args.part,
+            &input,
+            &options,
+            &mut std::io::stdout().lock(),
+        ),
+        Some(path) => {
+            let path = path.replace("{}", &day.to_string());
+            let file =
+                std::fs::File::create(&path).with_context(|| format!("can't write {:?}", path))?;
+            options.fps = None;
+            animate::render(day, args.part, &input, &options, &mut BufWriter::new(file))
+        }
+    };
+    res.map_err(|e| args.source.locate(day, e))?;
+    Ok(())
+}
+
+fn run_image(args: &Args) -> anyhow::Result<()> {
+    let Some(&[day]) = args.days.as_deref() else {
+        bail!("a single day to export is expected");
+    };
+    let input = args.source.read(day)?;
+    let image = image::export(day, args.part, &input).map_err(|e| args.source.locate(day, e))?;
+    match &args.output {
+        None if std::io::stdout().is_terminal() => {
+            bail!("an image can't be printed, use --output PATH")
+        }
+        None => image.write(&mut std::io::stdout().lock(), args.scale)?,
+        Some(path) => {
+            let path = path.replace("{}", &day.to_string());
+            let ext = image.extension();
+            if Path::new(&path).extension() != Some(ext.as_ref()) {
+                eprintln!(
+                    "warning: {:?} doesn't end with `.{}`, the image format",
+                    path, ext
+                );
+            }
+            std::fs::File::create(&path)
+                .and_then(|f| image.write(&mut BufWriter::new(f), args.scale))
+                .with_context(|| format!("can't write {:?}", path))?;
+        }
+    }
+    Ok(())
+}
+
+fn run_check(args: &Args) -> anyhow::Result<()> {
+    let single = match &args.source {
+        Source::Stdin => true,
+        Source::Path(p) => !p.contains("{}"),
+    };
+    if args.days.is_none() && single {
+        let input = args.source.read(0)?;
+        let days = days::guess(&input);
+        if days.is_empty() {
+            bail!("the input is not valid for any day");
+        }
+        for day in days {
+            println!("Day {}", day);
+        }
+        return Ok(());
+    }
+    let mut invalid = 0;
+    for day in args.days()? {
+        let input = args.source.read(day)?;
+        let violations = days::get(day)?
+            .validate(&input)
+            .map_err(|e| args.source.locate(day, e));
+        match violations {
+            Ok(v) if v.is_empty() => println!("Day {}: ok", day),
+            Ok(v) => {
+                invalid += 1;
+                println!("Day {}:", day);
+                for v in v {
+                    println!("    {}", v);
+                }
+            }
+            Err(e) => {
+                invalid += 1;
+                println!("Day {}: {:#}", day, e);
+            }
+        }
+    }
+    if invalid > 0 {
+        bail!("{} inputs are not valid", invalid);
+    }
+    Ok(())
+}
+
+fn run_params(args: &Args) -> anyhow::Result<()> {
+    let all = args.days.is_none();
+    for day in args.days.clone().unwrap_or_else(|| (1..=NB_DAYS).collect()) {
+        let params = days::get(day)?.params();
+        if all && params.is_empty() {
+            continue;
+        }
+        print!("Day {}:", day);
+        for (key, value) in params {
+            print!(" {}={}", key, value);
+        }
+        println!();
+    }
+    Ok(())
+}
+
+fn run_inventory(args: &Args) -> anyhow::Result<()> {
+    let elves = day01::inventory::read(&mut args.source.reader(1)?)
+        .map_err(|e| args.source.locate(1, e))?;
+    day01::inventory::report(&elves, args.top, &mut std::io::stdout().lock())?;
+    Ok(())
+}
+
+fn run_strategies(args: &Args) -> anyhow::Result<()> {
+    let input = args.source.read(2)?;
+    let (game, counts) = params::parse::<day02::Day02>(&input, &args.params)
+        .map_err(|e| args.source.locate(2, e))?;
+    day02::strategies::report(&game, &counts, &mut std::io::stdout().lock())?;
+    Ok(())
+}
+
+/// The entry point of the `aoc` binary.
+pub fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let name = args.next();
+    // the command, and its day for the commands of a single day
+    let (command, day): (fn(&Args) -> anyhow::Result<()>, _) = match name.as_deref() {
+        Some("run") => (run, None),
+        Some("bench") => (run_bench, None),
+        Some("generate") => (run_generate, None),
+        Some("animate") => (run_animate, None),
+        Some("image") => (run_image, None),
+        Some("check") => (run_check, None),
+        Some("params") => (run_params, None),
+        Some("inventory") => (run_inventory, Some(1)),
+        Some("strategies") => (run_strategies, Some(2)),
+        None | Some("-h" | "--help" | "help") => {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+        Some(cmd) => bail!("unknown command {:?}\n\n{}", cmd, USAGE),
+    };
+    let mut args = Args::parse(args)?;
+    if let Some(day) = day {
+        if args.days.as_ref().is_some_and(|d| d != &[day]) {
+            bail!("`aoc {}` is only for day {}", name.unwrap_or_default(), day);
+        }
+        args.days = Some(vec![day]);
+    }
+    args.check_params()?;
+    command(&args)
+}
+
+/// The entry point of the per day binaries: `NN [--input PATH|-] [--format text|json] [--param PARAM]...`.
+pub fn day_main(day: u8) -> anyhow::Result<()> {
+    let mut args = Args::parse(std::env::args().skip(1))?;
+    if args.days.as_ref().is_some_and(|d| d != &[day]) {
+        bail!("this binary only solves day {}", day);
+    }
+    args.days = Some(vec![day]);
+    args.check_params()?;
+    run(&args)
+}
@@ -0,0 +1,213 @@
+//! Cycle detection, to extrapolate long simulations.
+//!
+//! A simulation is given by its initial state and a step function,
+//! updating the state in place. The states are compared by a key (as
+//! the top of the tower for day 17), and must cycle at some point:
+//! after `start` steps, the key repeats every `period` steps. A metric
+//! growing by the same amount at each cycle (as the height of the
+//! tower) can then be extrapolated to any number of steps.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cycle of a simulation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before entering the cycle.
+    pub start: usize,
+    /// The length of the cycle.
+    pub period: usize,
+}
+
+/// The algorithm used to find a cycle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Method {
+    /// Floyd's tortoise and hare: two states, three steps per iteration.
+    Floyd,
+    /// Brent's algorithm: two states, fewer steps than Floyd.
+    #[default]
+    Brent,
+    /// Remember every key: one step per iteration, but memory hungry.
+    Hash,
+}
+
+/// The greatest common divisor, with Euclid's algorithm.
+pub fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, as the period of two cycles running
+/// together, 0 if one of them is 0.
+pub fn lcm(a: usize, b: usize) -> usize {
+    match gcd(a, b) {
+        0 => 0,
+        g => a / g * b,
+    }
+}
+
+/// Find a cycle with Floyd's tortoise and hare, if found by moving the
+/// tortoise at most `limit` steps.
+pub fn floyd<S: Clone, K: Eq>(
+    init: &S,
+    mut step: impl FnMut(&mut S),
+    key: impl Fn(&S) -> K,
+    limit: usize,
+) -> Option<Cycle> {
+    let mut tortoise = init.clone();
+    let mut hare = init.clone();
+    let mut nb = 0;
+    loop {
+        if nb >= limit {
+            return None;
+        }
+        step(&mut tortoise);
+        step(&mut hare);
+        step(&mut hare);
+        nb += 1;
+        if key(&tortoise) == key(&hare) {
+            break;
+        }
+    }
+
+    // the tortoise is at a multiple of the period, thus from `init`
+    // and the tortoise, the cycle is reached at the same time.
+    let mut start = 0;
+    let mut s = init.clone();
+    while key(&s) != key(&tortoise) {
+        step(&mut s);
+        step(&mut tortoise);
+        start += 1;
+    }
+
+    let mut period = 1;
+    step(&mut tortoise);
+    while key(&s) != key(&tortoise) {
+        step(&mut tortoise);
+        period += 1;
+    }
+    Some(Cycle { start, period })
+}
+
+/// Find a cycle with Brent's algorithm, if found by moving the hare at
+/// most `limit` steps.
+pub fn brent<S: Clone, K: Eq>(
+    init: &S,
+    mut step: impl FnMut(&mut S),
+    key: impl Fn(&S) -> K,
+    limit: usize,
+) -> Option<Cycle> {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = key(init);
+    let mut hare = init.clone();
+    step(&mut hare);
+    let mut nb = 1;
+    while tortoise != key(&hare) {
+        if nb >= limit {
+            return None;
+        }
+        if power == period {
+            tortoise = key(&hare);
+            power *= 2;
+            period = 0;
+        }
+        step(&mut hare);
+        period += 1;
+        nb += 1;
+    }
+
+    // the hare `period` steps ahead of the tortoise, they meet at the
+    // start of the cycle.
+    let mut tortoise = init.clone();
+    let mut hare = init.clone();
+    for _ in 0..period {
+        step(&mut hare);
+    }
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+    Some(Cycle { start, period })
+}
+
+/// Find a cycle by remembering the keys of the at most `limit` first steps.
+pub fn hashed<S: Clone, K: Eq + Hash>(
+    init: &S,
+    mut step: impl FnMut(&mut S),
+    key: impl Fn(&S) -> K,
+    limit: usize,
+) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    let mut s = init.clone();
+    for nb in 0..=limit {
+        if let Some(start) = seen.insert(key(&s), nb) {
+            return Some(Cycle {
+                start,
+                period: nb - start,
+            });
+        }
+        step(&mut s);
+    }
+    None
+}
+
+/// The value of a metric after a number of steps.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Extrapolation {
+    /// The cycle, if found before reaching the wanted number of steps.
+    pub cycle: Option<Cycle>,
+    /// The value of the metric after the wanted number of steps.
+    pub value: i64,
+}
+
+/// The value of `metric` after `target` steps, using `method` to skip
+/// the cycles.
+pub fn extrapolate<S: Clone, K: Eq + Hash>(
+    method: Method,
+    init: &S,
+    mut step: impl FnMut(&mut S),
+    key: impl Fn(&S) -> K,
+    metric: impl Fn(&S) -> i64,
+    target: usize,
+) -> Extrapolation {
+    let cycle = match method {
+        Method::Floyd => floyd(init, &mut step, &key, target),
+        Method::Brent => brent(init, &mut step, &key, target),
+        Method::Hash => hashed(init, &mut step, &key, target),
+    };
+    let Some(c) = cycle.filter(|c| c.start + c.period <= target) else {
+        let mut s = init.clone();
+        for _ in 0..target {
+            step(&mut s);
+        }
+        return Extrapolation {
+            cycle,
+            value: metric(&s),
+        };
+    };
+
+    let nb_cycles = ((target - c.start) / c.period) as i64;
+    let remaining = (target - c.start) % c.period;
+    let mut s = init.clone();
+    for _ in 0..c.start {
+        step(&mut s);
+    }
+    let at_start = metric(&s);
+    for _ in 0..remaining {
+        step(&mut s);
+    }
+    let at_remaining = metric(&s);
+    for _ in remaining..c.period {
+        step(&mut s);
+    }
+    let by_cycle = metric(&s) - at_start;
+    Extrapolation {
+        cycle,
+        value: at_remaining + nb_cycles * by_cycle,
+    }
+}
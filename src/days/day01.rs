@@ -0,0 +1,274 @@
+use crate::parse;
+use crate::solution::{Answer, Solution, Streaming};
+use std::io::BufRead;
+
+/// Insert `cur` in the `top` calories, sorted in decreasing order.
+fn keep_top(top: &mut [u64; 3], cur: u64) {
+    if let Some(i) = top.iter().position(|&c| cur > c) {
+        top[i..].rotate_right(1);
+        top[i] = cur;
+    }
+}
+
+/// Call `f` on the calories carried by each elf, in the order of the
+/// input, the elves being separated by blank lines.
+fn read_elves(input: &mut dyn BufRead, mut f: impl FnMut(u64)) -> anyhow::Result<()> {
+    parse::read_groups(input, |elf| {
+        let calories = elf
+            .lines
+            .iter()
+            .map(|l| l.parse::<u64>(l.text, "a number of calories"));
+        f(calories.sum::<Result<_, _>>()?);
+        Ok(())
+    })?;
+    Ok(())
+}
+
+/// The analysis of the calories carried by the elves, for `aoc
+/// inventory`.
+pub mod inventory {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+    use std::io::{self, BufRead, Write};
+    use std::ops::Range;
+
+    /// An elf, numbered from 1 in the order of the input.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Elf {
+        pub no: usize,
+        pub calories: u64,
+    }
+    impl Elf {
+        /// The elves carrying more being greater, then the first ones.
+        fn key(&self) -> (u64, Reverse<usize>) {
+            (self.calories, Reverse(self.no))
+        }
+    }
+
+    /// Read the elves.
+    pub fn read(input: &mut dyn BufRead) -> anyhow::Result<Vec<Elf>> {
+        let mut elves = vec![];
+        super::read_elves(input, |calories| {
+            let no = elves.len() + 1;
+            elves.push(Elf { no, calories })
+        })?;
+        Ok(elves)
+    }
+
+    /// The `n` elves carrying the most calories, kept in a heap of `n`
+    /// elves while they are given one after the other.
+    #[derive(Debug, Clone)]
+    pub struct Top {
+        n: usize,
+        /// A min heap, its first elf being the first one to leave.
+        heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+        /// The elves left out carrying as much as the first elf of the heap.
+        ties: Vec<Elf>,
+    }
+    impl Top {
+        pub fn new(n: usize) -> Self {
+            Self {
+                n,
+                heap: BinaryHeap::with_capacity(n + 1),
+                ties: vec![],
+            }
+        }
+        /// Consider an elf, given after the elves of smaller numbers.
+        pub fn push(&mut self, elf: Elf) {
+            if self.n == 0 {
+                return;
+            }
+            self.heap.push(Reverse(elf.key()));
+            if self.heap.len() <= self.n {
+                return;
+            }
+            let Some(Reverse((calories, Reverse(no)))) = self.heap.pop() else {
+                return;
+            };
+            let min = self.heap.peek().map_or(0, |Reverse((c, _))| *c);
+            if self.ties.first().is_some_and(|t| t.calories < min) {
+                self.ties.clear();
+            }
+            if calories == min {
+                self.ties.push(Elf { no, calories });
+            }
+        }
+        /// The elves of the top, carrying the most first, the first ones
+        /// first when carrying as much.
+        pub fn elves(&self) -> Vec<Elf> {
+            let mut elves: Vec<_> = self.heap.iter().map(|Reverse(k)| *k).collect();
+            elves.sort_by(|a, b| b.cmp(a));
+            elves
+                .into_iter()
+                .map(|(calories, Reverse(no))| Elf { no, calories })
+                .collect()
+        }
+        /// The elves left out of the top while carrying as much as its
+        /// last elf, in order.
+        pub fn ties(&self) -> Vec<Elf> {
+            let mut ties = self.ties.clone();
+            ties.sort_by_key(|e| e.no);
+            ties
+        }
+    }
+
+    /// The `n` elves carrying the most calories.
+    pub fn top(elves: impl IntoIterator<Item = Elf>, n: usize) -> Top {
+        let mut top = Top::new(n);
+        for elf in elves {
+            top.push(elf);
+        }
+        top
+    }
+
+    /// Summary statistics of the calories carried by the elves.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Stats {
+        pub nb_elves: usize,
+        pub total: u64,
+        pub mean: f64,
+        /// The calories of each elf, sorted.
+        sorted: Vec<u64>,
+    }
+    impl Stats {
+        /// The statistics of the elves, `None` without elves.
+        pub fn new(elves: &[Elf]) -> Option<Self> {
+            if elves.is_empty() {
+                return None;
+            }
+            let mut sorted: Vec<_> = elves.iter().map(|e| e.calories).collect();
+            sorted.sort_unstable();
+            let total = sorted.iter().sum::<u64>();
+            Some(Self {
+                nb_elves: sorted.len(),
+                total,
+                mean: total as f64 / sorted.len() as f64,
+                sorted,
+            })
+        }
+        pub fn min(&self) -> u64 {
+            self.sorted[0]
+        }
+        pub fn max(&self) -> u64 {
+            self.sorted[self.sorted.len() - 1]
+        }
+        pub fn median(&self) -> f64 {
+            self.percentile(50.)
+        }
+        /// The `p`th percentile, `p` being in `0..=100`, interpolated
+        /// between the closest elves.
+        pub fn percentile(&self, p: f64) -> f64 {
+            let rank = p.clamp(0., 100.) / 100. * (self.sorted.len() - 1) as f64;
+            let (i, f) = (rank.floor() as usize, rank.fract());
+            let next = self.sorted.get(i + 1).unwrap_or(&self.sorted[i]);
+            self.sorted[i] as f64 * (1. - f) + *next as f64 * f
+        }
+        /// The numbers of elves in at most `bins` ranges of calories of
+        /// the same width, from the min to the max.
+        pub fn histogram(&self, bins: usize) -> Vec<(Range<u64>, usize)> {
+            let (min, max) = (self.min(), self.max());
+            let width = (max - min + 1).div_ceil(bins.max(1) as u64);
+            let mut res: Vec<_> = (0..=(max - min) / width)
+                .map(|i| (min + i * width..min + (i + 1) * width, 0))
+                .collect();
+            for &c in &self.sorted {
+                res[((c - min) / width) as usize].1 += 1;
+            }
+            res
+        }
+    }
+
+    /// Write the statistics of the elves and the `n` elves carrying the
+    /// most calories.
+    pub fn report(elves: &[Elf], n: usize, out: &mut dyn Write) -> io::Result<()> {
+        let Some(stats) = Stats::new(elves) else {
+            return writeln!(out, "No elves");
+        };
+        writeln!(
+            out,
+            "Elves: {}, carrying {} calories",
+            stats.nb_elves, stats.total
+        )?;
+        writeln!(
+            out,
+            "Mean: {:.1}, median: {:.1}, min: {}, max: {}",
+            stats.mean,
+            stats.median(),
+            stats.min(),
+            stats.max()
+        )?;
+        let percentiles: Vec<_> = [10., 25., 75., 90., 99.]
+            .iter()
+            .map(|&p| format!("{}%: {:.1}", p, stats.percentile(p)))
+            .collect();
+        writeln!(out, "Percentiles: {}", percentiles.join(", "))?;
+
+        let top = top(elves.iter().copied(), n);
+        let best = top.elves();
+        writeln!(out, "Top {}:", best.len())?;
+        for (i, elf) in best.iter().enumerate() {
+            let tied = best.iter().filter(|e| e.calories == elf.calories).count() > 1;
+            let tied = if tied { " (tie)" } else { "" };
+            writeln!(
+                out,
+                "{:>5}. elf {}: {}{}",
+                i + 1,
+                elf.no,
+                elf.calories,
+                tied
+            )?;
+        }
+        let ties = top.ties();
+        if !ties.is_empty() {
+            let nos: Vec<_> = ties.iter().map(|e| e.no.to_string()).collect();
+            let elves = if nos.len() == 1 { "elf" } else { "elves" };
+            writeln!(out, "Left out with as much: {} {}", elves, nos.join(", "))?;
+        }
+
+        writeln!(out, "Histogram:")?;
+        let histogram = stats.histogram(10);
+        let most = histogram
+            .iter()
+            .map(|&(_, nb)| nb)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        for (range, nb) in histogram {
+            let bar = "#".repeat((nb * 40).div_ceil(most));
+            let range = format!("{}..{}", range.start, range.end);
+            let line = format!("{:>15} {:>5} {}", range, nb, bar);
+            writeln!(out, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day01;
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const STREAMING: Streaming = Streaming::SinglePass;
+    /// The 3 most calories carried by an elf, in decreasing order, kept
+    /// while reading the elves.
+    type Input = [u64; 3];
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_reader(&mut input.as_bytes())
+    }
+    fn parse_reader(input: &mut dyn BufRead) -> anyhow::Result<Self::Input> {
+        let mut top = [0; 3];
+        read_elves(input, |cur| keep_top(&mut top, cur))?;
+        Ok(top)
+    }
+    fn validate(input: &str, _: &Self::Input) -> Vec<String> {
+        match parse::groups(input).count() {
+            nb if nb < 3 => vec![format!("at least 3 elves expected, got {}", nb)],
+            _ => vec![],
+        }
+    }
+    fn part1(top: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(top[0].into())
+    }
+    fn part2(top: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(top.iter().sum::<u64>().into())
+    }
+}
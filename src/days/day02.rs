@@ -0,0 +1,374 @@
+use crate::params::List;
+use crate::parse::{self, Line, ParseError};
+use crate::solution::{Answer, Solution, Streaming};
+use anyhow::bail;
+use std::io::BufRead;
+
+crate::params! {
+    /// The rules of the game, rock, paper, scissors by default.
+    pub struct Params {
+        /// The letters of the moves of the opponent, each move beating
+        /// the half of the moves before it, cyclically.
+        pub them: String = "ABC".into(),
+        /// Our letters, of the moves in the first part, and of the
+        /// shifts of the move of the opponent in the second part.
+        pub us: String = "XYZ".into(),
+        /// The scores of playing each move.
+        pub scores: List<u64> = List(vec![1, 2, 3]),
+        /// The scores of losing, drawing and winning.
+        pub outcomes: List<u64> = List(vec![0, 3, 6]),
+    }
+}
+
+/// The outcome of a round, for us.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// A cyclic game of an odd number of moves, each move beating the half
+/// of the moves before it and losing against the half after it, as
+/// rock, paper, scissors, or rock, Spock, paper, lizard, scissors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    /// The letters of the moves of the opponent.
+    pub them: Vec<u8>,
+    /// Our letters, for each move in the first part, and for each shift
+    /// of the move of the opponent, from `-n / 2` to `n / 2`, in the
+    /// second part (lose, draw and win for 3 moves).
+    pub us: Vec<u8>,
+    /// The scores of playing each move.
+    pub scores: Vec<u64>,
+    /// The scores of losing, drawing and winning.
+    pub outcomes: [u64; 3],
+}
+impl Game {
+    pub fn new(params: &Params) -> anyhow::Result<Self> {
+        let n = params.them.len();
+        if n.is_multiple_of(2) {
+            bail!("an odd number of moves expected, got {}", n);
+        }
+        for letters in [&params.them, &params.us] {
+            if letters.len() != n {
+                bail!("{} letters expected, got {:?}", n, letters);
+            }
+            let b = letters.as_bytes();
+            if !b.iter().all(u8::is_ascii_graphic) || (1..n).any(|i| b[..i].contains(&b[i])) {
+                bail!("distinct letters expected, got {:?}", letters);
+            }
+        }
+        if params.scores.0.len() != n {
+            bail!("{} move scores expected, got {}", n, params.scores);
+        }
+        let Ok(outcomes) = params.outcomes.0.clone().try_into() else {
+            bail!("3 outcome scores expected, got {}", params.outcomes);
+        };
+        Ok(Game {
+            them: params.them.as_bytes().to_vec(),
+            us: params.us.as_bytes().to_vec(),
+            scores: params.scores.0.clone(),
+            outcomes,
+        })
+    }
+    pub fn nb_moves(&self) -> usize {
+        self.them.len()
+    }
+    /// Our outcome when playing `us` against `them`.
+    pub fn outcome(&self, them: usize, us: usize) -> Outcome {
+        let n = self.nb_moves();
+        match (us + n - them) % n {
+            0 => Outcome::Draw,
+            d if d <= n / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+    /// Our score when playing `us` against `them`.
+    pub fn score(&self, them: usize, us: usize) -> u64 {
+        self.scores[us] + self.outcomes[self.outcome(them, us) as usize]
+    }
+    /// Our move for our letter `i` in the second part, shifting the move
+    /// of the opponent.
+    pub fn shifted(&self, them: usize, i: usize) -> usize {
+        let n = self.nb_moves();
+        (them + n + i - n / 2) % n
+    }
+}
+
+/// The letters, as `` `A`, `B` or `C` ``.
+fn one_of(letters: &[u8]) -> String {
+    let letters: Vec<_> = letters
+        .iter()
+        .map(|&c| format!("`{}`", c as char))
+        .collect();
+    match letters.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        _ => letters.concat(),
+    }
+}
+
+/// The moves of a round, as `(them, us)`.
+fn round(game: &Game, l: Line) -> Result<(usize, usize), ParseError> {
+    let b = l.text.as_bytes();
+    let Some(them) = b
+        .first()
+        .and_then(|c| game.them.iter().position(|t| t == c))
+    else {
+        return Err(l.error_at(0..1, one_of(&game.them)));
+    };
+    if b.get(1) != Some(&b' ') {
+        return Err(l.error_at(1..2, "a space"));
+    }
+    match b[2..] {
+        [c] => match game.us.iter().position(|&u| u == c) {
+            Some(us) => Ok((them, us)),
+            None => Err(l.error_at(2..3, one_of(&game.us))),
+        },
+        _ => Err(l.error_at(2..b.len(), one_of(&game.us))),
+    }
+}
+
+/// The other ways to read our letters of a strategy guide, for `aoc
+/// strategies`.
+pub mod strategies {
+    use super::Game;
+    use anyhow::bail;
+    use std::io::Write;
+
+    /// The most moves for which every reading is tried, 5 moves having
+    /// 120 readings of each meaning.
+    pub const MAX_MOVES: usize = 5;
+
+    /// What our letters mean.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum Meaning {
+        /// Our move, as in the first part.
+        Move,
+        /// A shift of the move of the opponent, as in the second part.
+        Shift,
+    }
+
+    /// A reading of our letters, a bijection of the letters to the moves
+    /// or to the shifts.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Interpretation {
+        pub meaning: Meaning,
+        /// The move or the shift of each of our letters.
+        pub of: Vec<usize>,
+    }
+    impl Interpretation {
+        /// The reading of the puzzle, each letter meaning the move or the
+        /// shift of the same rank.
+        pub fn puzzle(game: &Game, meaning: Meaning) -> Self {
+            Self {
+                meaning,
+                of: (0..game.nb_moves()).collect(),
+            }
+        }
+        /// All the readings, the one of the puzzle first, then in
+        /// lexicographic order. There are `n!` of them for `n` moves,
+        /// so at most [`MAX_MOVES`] moves are accepted.
+        pub fn all(game: &Game, meaning: Meaning) -> anyhow::Result<Vec<Self>> {
+            let n = game.nb_moves();
+            if n > MAX_MOVES {
+                bail!(
+                    "every reading of the letters can only be tried for at most {} moves, got {}",
+                    MAX_MOVES,
+                    n
+                );
+            }
+            let mut of: Vec<usize> = (0..n).collect();
+            let mut res = vec![];
+            loop {
+                res.push(Self {
+                    meaning,
+                    of: of.clone(),
+                });
+                // the next permutation
+                let Some(i) = (1..n).rev().find(|&i| of[i - 1] < of[i]) else {
+                    return Ok(res);
+                };
+                let j = (i..n).rev().find(|&j| of[j] > of[i - 1]).unwrap();
+                of.swap(i - 1, j);
+                of[i..].reverse();
+            }
+        }
+        /// Our move for our letter `us` against the move `them`.
+        pub fn our_move(&self, game: &Game, them: usize, us: usize) -> usize {
+            match self.meaning {
+                Meaning::Move => self.of[us],
+                Meaning::Shift => game.shifted(them, self.of[us]),
+            }
+        }
+        /// The total score of the rounds, counted by the letters of the
+        /// opponent, then ours.
+        pub fn score(&self, game: &Game, counts: &[Vec<u64>]) -> u64 {
+            rounds(counts)
+                .map(|(them, us, nb)| game.score(them, self.our_move(game, them, us)) * nb)
+                .sum()
+        }
+        /// The reading, as `X=B, Y=A, Z=C` for the moves of the letters
+        /// of the opponent, or `X=+1, Y=-1, Z=+0` for the shifts.
+        pub fn describe(&self, game: &Game) -> String {
+            let half = (game.nb_moves() / 2) as i64;
+            let of: Vec<_> = game
+                .us
+                .iter()
+                .zip(&self.of)
+                .map(|(&us, &i)| match self.meaning {
+                    Meaning::Move => format!("{}={}", us as char, game.them[i] as char),
+                    Meaning::Shift => format!("{}={:+}", us as char, i as i64 - half),
+                })
+                .collect();
+            of.join(", ")
+        }
+    }
+
+    /// The rounds played, as `(them, us, nb)`.
+    pub fn rounds(counts: &[Vec<u64>]) -> impl Iterator<Item = (usize, usize, u64)> + '_ {
+        counts.iter().enumerate().flat_map(|(them, counts)| {
+            counts
+                .iter()
+                .enumerate()
+                .filter(|&(_, &nb)| nb > 0)
+                .map(move |(us, &nb)| (them, us, nb))
+        })
+    }
+
+    /// The scores of all the readings of a meaning.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Analysis {
+        pub meaning: Meaning,
+        /// Each reading with its score, the one of the puzzle first.
+        pub scores: Vec<(Interpretation, u64)>,
+    }
+    impl Analysis {
+        pub fn new(game: &Game, counts: &[Vec<u64>], meaning: Meaning) -> anyhow::Result<Self> {
+            let scores = Interpretation::all(game, meaning)?
+                .into_iter()
+                .map(|i| {
+                    let score = i.score(game, counts);
+                    (i, score)
+                })
+                .collect();
+            Ok(Self { meaning, scores })
+        }
+        /// The reading of the puzzle and its score.
+        pub fn puzzle(&self) -> &(Interpretation, u64) {
+            &self.scores[0]
+        }
+        /// The first reading with the best score.
+        pub fn best(&self) -> &(Interpretation, u64) {
+            self.scores.iter().rev().max_by_key(|(_, s)| s).unwrap()
+        }
+        /// The first reading with the worst score.
+        pub fn worst(&self) -> &(Interpretation, u64) {
+            self.scores.iter().min_by_key(|(_, s)| s).unwrap()
+        }
+        /// The mean score of a reading chosen at random.
+        pub fn expected(&self) -> f64 {
+            let total: u64 = self.scores.iter().map(|(_, s)| s).sum();
+            total as f64 / self.scores.len() as f64
+        }
+    }
+
+    /// Write the best, worst and expected scores of the readings of our
+    /// letters as moves, then as shifts, with the scores of each round.
+    pub fn report(game: &Game, counts: &[Vec<u64>], out: &mut dyn Write) -> anyhow::Result<()> {
+        let analyses = [
+            Analysis::new(game, counts, Meaning::Move)?,
+            Analysis::new(game, counts, Meaning::Shift)?,
+        ];
+        let nb: u64 = rounds(counts).map(|(_, _, nb)| nb).sum();
+        writeln!(out, "Rounds: {}", nb)?;
+        for (analysis, part) in analyses.iter().zip(1..) {
+            let meaning = analysis.meaning;
+            let what = match meaning {
+                Meaning::Move => "moves",
+                Meaning::Shift => "shifts of the move of the opponent",
+            };
+            writeln!(out, "\nOur letters as {}:", what)?;
+            let readings = [
+                (format!("part {}", part), analysis.puzzle()),
+                ("best".into(), analysis.best()),
+                ("worst".into(), analysis.worst()),
+            ];
+            for (name, (i, score)) in &readings {
+                let name = format!("{}:", name);
+                writeln!(out, "{:>10} {:>8} with {}", name, score, i.describe(game))?;
+            }
+            writeln!(
+                out,
+                "{:>10} {:>8.1} over {} readings",
+                "expected:",
+                analysis.expected(),
+                analysis.scores.len()
+            )?;
+            writeln!(
+                out,
+                "{:>10} {:>8} {:>8} {:>8} {:>8}",
+                "round", "count", readings[0].0, "best", "worst"
+            )?;
+            for (them, us, nb) in rounds(counts) {
+                let round = format!("{} {}", game.them[them] as char, game.us[us] as char);
+                let points = readings[..]
+                    .iter()
+                    .map(|(_, (i, _))| {
+                        format!(" {:>8}", game.score(them, i.our_move(game, them, us)) * nb)
+                    })
+                    .collect::<String>();
+                writeln!(out, "{:>10} {:>8}{}", round, nb, points)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Day02;
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const STREAMING: Streaming = Streaming::Lines;
+    /// The game, and the number of times each round is played, by the
+    /// letters of the opponent, then ours.
+    type Input = (Game, Vec<Vec<u64>>);
+    type Params = Params;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_reader(&mut input.as_bytes())
+    }
+    fn parse_with(input: &str, params: &Params) -> anyhow::Result<Self::Input> {
+        Self::parse_reader_with(&mut input.as_bytes(), params)
+    }
+    fn parse_reader(input: &mut dyn BufRead) -> anyhow::Result<Self::Input> {
+        Self::parse_reader_with(input, &Params::default())
+    }
+    fn parse_reader_with(input: &mut dyn BufRead, params: &Params) -> anyhow::Result<Self::Input> {
+        let game = Game::new(params)?;
+        let n = game.nb_moves();
+        let mut counts = vec![vec![0; n]; n];
+        parse::read_lines(input, |l| {
+            let (them, us) = round(&game, l)?;
+            counts[them][us] += 1;
+            Ok(())
+        })?;
+        Ok((game, counts))
+    }
+    fn part1((game, counts): &Self::Input) -> anyhow::Result<Answer> {
+        let mut score = 0;
+        for (them, counts) in counts.iter().enumerate() {
+            for (us, &nb) in counts.iter().enumerate() {
+                score += game.score(them, us) * nb;
+            }
+        }
+        Ok(score.into())
+    }
+    fn part2((game, counts): &Self::Input) -> anyhow::Result<Answer> {
+        let mut score = 0;
+        for (them, counts) in counts.iter().enumerate() {
+            for (i, &nb) in counts.iter().enumerate() {
+                score += game.score(them, game.shifted(them, i)) * nb;
+            }
+        }
+        Ok(score.into())
+    }
+}
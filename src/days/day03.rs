@@ -0,0 +1,182 @@
+use crate::parse;
+use crate::solution::{Answer, Solution, Streaming};
+use anyhow::bail;
+use std::io::BufRead;
+
+/// The priority of an item, from 1 for `a` to 52 for `Z`, `None` if
+/// not an item.
+pub fn priority(item: u8) -> Option<u32> {
+    match item {
+        b'a'..=b'z' => Some((item - b'a' + 1) as u32),
+        b'A'..=b'Z' => Some((item - b'A' + 27) as u32),
+        _ => None,
+    }
+}
+
+/// A set of items, as a bit per priority.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Items(u64);
+impl Items {
+    /// Every item, from `a` to `z` and `A` to `Z`.
+    pub const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    /// Insert an item, failing if it is not one.
+    pub fn insert(&mut self, item: u8) -> anyhow::Result<()> {
+        let Some(p) = priority(item) else {
+            bail!(
+                "{:?} is not an item, from `a` to `z` or `A` to `Z`",
+                item as char
+            );
+        };
+        self.0 |= 1 << p;
+        Ok(())
+    }
+    pub fn contains(&self, item: u8) -> bool {
+        priority(item).is_some_and(|p| self.0 & 1 << p != 0)
+    }
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+    /// The items in all the sets, every item without sets.
+    pub fn common(sets: impl IntoIterator<Item = Items>) -> Items {
+        sets.into_iter().fold(Items::ALL, Items::intersection)
+    }
+    /// The priorities of the items, in increasing order.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |p| self.0 & 1 << p != 0)
+    }
+}
+impl TryFrom<&[u8]> for Items {
+    type Error = anyhow::Error;
+    fn try_from(items: &[u8]) -> anyhow::Result<Self> {
+        let mut res = Items::default();
+        for &item in items {
+            res.insert(item)?;
+        }
+        Ok(res)
+    }
+}
+
+crate::params! {
+    /// The size of the groups of elves.
+    pub struct Params {
+        /// The number of rucksacks of a group, sharing a single badge.
+        pub group: usize = 3,
+    }
+}
+
+/// The sums of the priorities, kept while reading the rucksacks.
+#[derive(Debug, Clone)]
+pub struct Priorities {
+    /// Of the items in both compartments.
+    misplaced: u32,
+    /// Of the badges of the complete groups.
+    badges: u32,
+    /// The number of rucksacks read.
+    nb: usize,
+    /// The items common to the rucksacks of the current group.
+    group: Items,
+    /// The first group without a single badge, as its first line and
+    /// its number of common items.
+    bad_group: Option<(usize, usize)>,
+}
+
+pub struct Day03;
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const STREAMING: Streaming = Streaming::SinglePass;
+    /// The sums of the priorities, and the size of the groups.
+    type Input = (Priorities, Params);
+    type Params = Params;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_reader(&mut input.as_bytes())
+    }
+    fn parse_with(input: &str, params: &Params) -> anyhow::Result<Self::Input> {
+        Self::parse_reader_with(&mut input.as_bytes(), params)
+    }
+    fn parse_reader(input: &mut dyn BufRead) -> anyhow::Result<Self::Input> {
+        Self::parse_reader_with(input, &Params::default())
+    }
+    fn parse_reader_with(input: &mut dyn BufRead, params: &Params) -> anyhow::Result<Self::Input> {
+        if params.group == 0 {
+            bail!("groups of at least 1 rucksack expected");
+        }
+        let size = params.group;
+        let mut res = Priorities {
+            misplaced: 0,
+            badges: 0,
+            nb: 0,
+            group: Items::ALL,
+            bad_group: None,
+        };
+        parse::read_lines(input, |l| {
+            if let Some(i) = l.text.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(l
+                    .error_at(i..i + 1, "an item, from `a` to `z` or `A` to `Z`")
+                    .into());
+            }
+            let rucksack = l.text.as_bytes();
+            if rucksack.len() % 2 != 0 {
+                return Err(l
+                    .error_line("an even number of items, in 2 compartments of the same size")
+                    .into());
+            }
+            let (left, right) = rucksack.split_at(rucksack.len() / 2);
+            let (left, right) = (Items::try_from(left)?, Items::try_from(right)?);
+            let misplaced = left.intersection(right);
+            if misplaced.len() != 1 {
+                let e = format!(
+                    "a single item in both compartments, got {}",
+                    misplaced.len()
+                );
+                return Err(l.error_line(e).into());
+            }
+            res.misplaced += misplaced.priorities().sum::<u32>();
+            res.group = res.group.intersection(left.union(right));
+            res.nb += 1;
+            if res.nb.is_multiple_of(size) {
+                // the bad groups are errors of the second part only
+                match res.group.len() {
+                    1 => res.badges += res.group.priorities().sum::<u32>(),
+                    nb => {
+                        res.bad_group.get_or_insert((res.nb + 1 - size, nb));
+                    }
+                }
+                res.group = Items::ALL;
+            }
+            Ok(())
+        })?;
+        Ok((res, params.clone()))
+    }
+    fn part1((p, _): &Self::Input) -> anyhow::Result<Answer> {
+        Ok(p.misplaced.into())
+    }
+    fn part2((p, params): &Self::Input) -> anyhow::Result<Answer> {
+        let size = params.group;
+        if p.nb % size != 0 {
+            bail!(
+                "{} more rucksacks expected, in groups of {}",
+                size - p.nb % size,
+                size
+            );
+        }
+        if let Some((first, nb)) = p.bad_group {
+            bail!(
+                "lines {} to {}: a single item common to the rucksacks of the group expected, got {}",
+                first,
+                first + size - 1,
+                nb
+            );
+        }
+        Ok(p.badges.into())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::parse;
+use crate::solution::{Answer, Solution, Streaming};
+use std::io::BufRead;
+
+fn is_included([b1, e1, b2, e2]: [u32; 4]) -> bool {
+    b1 <= b2 && e2 <= e1 || b2 <= b1 && e1 <= e2
+}
+
+fn overlap([b1, e1, b2, e2]: [u32; 4]) -> bool {
+    !(e1 < b2 || e2 < b1)
+}
+
+pub struct Day04;
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const STREAMING: Streaming = Streaming::SinglePass;
+    /// The numbers of pairs with a range including the other, and of
+    /// pairs with overlapping ranges, counted while reading the pairs.
+    type Input = [usize; 2];
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_reader(&mut input.as_bytes())
+    }
+    fn parse_reader(input: &mut dyn BufRead) -> anyhow::Result<Self::Input> {
+        let mut counts = [0; 2];
+        parse::read_lines(input, |l| {
+            let v: Vec<u32> = l
+                .text
+                .split(&['-', ','])
+                .map(|s| l.parse::<u32>(s, "a section number"))
+                .collect::<Result<Vec<_>, _>>()?;
+            let entry = <[_; 4]>::try_from(v.as_slice())
+                .map_err(|_| l.error_line("two ranges of sections, as `2-4,6-8`"))?;
+            counts[0] += is_included(entry) as usize;
+            counts[1] += overlap(entry) as usize;
+            Ok(())
+        })?;
+        Ok(counts)
+    }
+    fn part1(counts: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(counts[0].into())
+    }
+    fn part2(counts: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(counts[1].into())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::parse::{self, Line, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+
+#[derive(Copy, Clone)]
+pub struct Move {
+    nb: usize,
+    from: usize,
+    to: usize,
+}
+
+fn is_crate(c: u8) -> bool {
+    c.is_ascii_uppercase()
+}
+
+fn create_stack(lines: &[Line]) -> Vec<Vec<u8>> {
+    let mut res = vec![];
+    for l in lines {
+        let mut l = l.text.as_bytes().to_vec();
+        let mut idx = 0;
+        l.retain(|_| {
+            idx += 1;
+            idx % 4 == 2
+        });
+        res.resize(res.len().max(l.len()), vec![]);
+        for (i, c) in l.into_iter().enumerate() {
+            if is_crate(c) {
+                res[i].push(c);
+            }
+        }
+    }
+    for v in &mut res {
+        v.reverse();
+    }
+    res
+}
+
+impl Move {
+    /// Parse a move, applying it to the `heights` of the stacks.
+    fn parse(l: Line, heights: &mut [usize]) -> Result<Move, ParseError> {
+        let nb_stacks = heights.len();
+        let stack = |s| {
+            let expected = format!("a stack number, from 1 to {}", nb_stacks);
+            match l.parse::<usize>(s, &expected)? {
+                i if (1..=nb_stacks).contains(&i) => Ok(i - 1),
+                _ => Err(l.error(s, expected)),
+            }
+        };
+        match *l.text.split(' ').collect::<Vec<_>>() {
+            ["move", nb, "from", from, "to", to] => {
+                let m = Move {
+                    nb: l.parse(nb, "a number of crates")?,
+                    from: stack(from)?,
+                    to: stack(to)?,
+                };
+                if heights[m.from] < m.nb {
+                    return Err(l.error(
+                        nb,
+                        format!(
+                            "at most {} crates, the height of stack {}",
+                            heights[m.from],
+                            m.from + 1
+                        ),
+                    ));
+                }
+                heights[m.from] -= m.nb;
+                heights[m.to] += m.nb;
+                Ok(m)
+            }
+            _ => Err(l.error_line("`move N from A to B`")),
+        }
+    }
+}
+
+fn make_move_9000(s: &mut [Vec<u8>], m: Move) {
+    for _ in 0..m.nb {
+        let Some(c) = s[m.from].pop() else { return };
+        s[m.to].push(c);
+    }
+}
+
+fn make_move_9001(s: &mut [Vec<u8>], m: Move) {
+    make_move_9000(s, m);
+    let v = &mut s[m.to];
+    let len = v.len();
+    v[len - m.nb..].reverse();
+}
+
+fn rearrange((stack, moves): &(Vec<Vec<u8>>, Vec<Move>), f: fn(&mut [Vec<u8>], Move)) -> String {
+    let mut stack = stack.clone();
+    for &m in moves {
+        f(&mut stack, m);
+        let to = &stack[m.to];
+        let moved = || String::from_utf8_lossy(&to[to.len().saturating_sub(m.nb)..]);
+        trace!(
+            5,
+            "move",
+            nb = m.nb,
+            from = m.from + 1,
+            to = m.to + 1,
+            moved = moved()
+        );
+    }
+
+    stack
+        .iter()
+        .map(|v| v.last().copied().unwrap_or(b' ') as char)
+        .collect()
+}
+
+pub struct Day05;
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = (Vec<Vec<u8>>, Vec<Move>);
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut groups = parse::groups(input);
+        let Some(drawing) = groups.next() else {
+            return Err(parse::eof(input, "the drawing of the stacks").into());
+        };
+        let stack = create_stack(&drawing.lines);
+        let mut heights: Vec<_> = stack.iter().map(|s| s.len()).collect();
+        let moves = match groups.next() {
+            Some(g) => g
+                .lines
+                .iter()
+                .map(|&l| Move::parse(l, &mut heights).map_err(|e| g.locate(e)))
+                .collect::<Result<_, _>>()?,
+            None => vec![],
+        };
+        if let Some(g) = groups.next() {
+            let e =
+                g.lines[0].error_line("a move right after the previous one, without a blank line");
+            return Err(g.locate(e).into());
+        }
+        Ok((stack, moves))
+    }
+    fn validate(input: &str, (stack, _): &Self::Input) -> Vec<String> {
+        let mut res = vec![];
+        // the drawing ends with the numbers of the stacks
+        let numbers = parse::groups(input)
+            .next()
+            .and_then(|g| g.lines.last().copied());
+        let expected = (1..=stack.len()).map(|i| i.to_string());
+        if !numbers.is_some_and(|l| l.text.split_whitespace().eq(expected)) {
+            res.push(format!(
+                "the stacks numbered from 1 to {} expected",
+                stack.len()
+            ));
+        }
+        res
+    }
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(rearrange(input, make_move_9000).into())
+    }
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(rearrange(input, make_move_9001).into())
+    }
+}
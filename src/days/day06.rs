@@ -0,0 +1,38 @@
+use crate::solution::{Answer, Solution};
+
+fn all_unique(buf: &[u8]) -> bool {
+    buf.iter()
+        .enumerate()
+        .all(|(i, c1)| buf[..i].iter().all(|c2| c1 != c2))
+}
+
+fn find_first(buf: &[u8], size: usize) -> anyhow::Result<usize> {
+    for (i, c) in buf.windows(size).enumerate() {
+        if all_unique(c) {
+            return Ok(i + size);
+        }
+    }
+    anyhow::bail!("not found")
+}
+
+pub struct Day06;
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Vec<u8>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.trim_end().as_bytes().to_vec())
+    }
+    fn validate(_: &str, buf: &Self::Input) -> Vec<String> {
+        match buf.iter().position(|c| !c.is_ascii_lowercase()) {
+            Some(i) => vec![format!("a letter from `a` to `z` expected at {}", i + 1)],
+            None => vec![],
+        }
+    }
+    fn part1(buf: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(find_first(buf, 4)?.into())
+    }
+    fn part2(buf: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(find_first(buf, 14)?.into())
+    }
+}
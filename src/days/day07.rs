@@ -0,0 +1,61 @@
+use crate::parse::{self, Line, ParseError};
+use crate::solution::{Answer, Solution};
+
+fn visit<'a>(
+    lines: &mut impl std::iter::FusedIterator<Item = Line<'a>>,
+    f: &mut impl FnMut(u32),
+) -> Result<u32, ParseError> {
+    let mut cur_size = 0;
+    while let Some(line) = lines.next() {
+        match *line.text.split(' ').collect::<Vec<_>>() {
+            ["$", "cd", "/"] => (),
+            ["$", "cd", ".."] => break,
+            ["$", "cd", _] => cur_size += visit(lines, f)?,
+            ["$", "ls"] => (),
+            ["dir", _] => (),
+            [size, _] => cur_size += line.parse::<u32>(size, "a file size or `dir`")?,
+            _ => return Err(line.error_line("`$ cd DIR`, `$ ls`, `dir NAME` or `SIZE NAME`")),
+        }
+    }
+    f(cur_size);
+    Ok(cur_size)
+}
+
+crate::params! {
+    /// The sizes of the puzzle.
+    pub struct Params {
+        /// The maximal size of the directories summed in the first part.
+        pub small: u32 = 100_000,
+        /// The size of the disk.
+        pub disk: u32 = 70_000_000,
+        /// The unused space needed by the update.
+        pub needed: u32 = 30_000_000,
+    }
+}
+
+pub struct Day07;
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    /// The size of every directory, the root being the last one.
+    type Input = (Vec<u32>, Params);
+    type Params = Params;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+    fn parse_with(input: &str, params: &Params) -> anyhow::Result<Self::Input> {
+        let mut sizes = vec![];
+        visit(&mut parse::lines(input).fuse(), &mut |cur_size| {
+            sizes.push(cur_size)
+        })?;
+        Ok((sizes, params.clone()))
+    }
+    fn part1((sizes, p): &Self::Input) -> anyhow::Result<Answer> {
+        Ok(sizes.iter().filter(|&&s| s <= p.small).sum::<u32>().into())
+    }
+    fn part2((sizes, p): &Self::Input) -> anyhow::Result<Answer> {
+        let total_size = sizes.last().copied().unwrap_or(0);
+        let target = p.needed.saturating_sub(p.disk.saturating_sub(total_size));
+        let to_remove_size = sizes.iter().filter(|&&s| s >= target).min();
+        Ok(to_remove_size.copied().unwrap_or(total_size).into())
+    }
+}
@@ -0,0 +1,79 @@
+use crate::grid::{Coord, Grid, DIRS4};
+use crate::image::Image;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+fn look(h: &Grid<u8>, v: &mut Grid<bool>, iter: impl Iterator<Item = Coord>) {
+    let mut cur = 0;
+    for c in iter {
+        let h = h[c];
+        if h > cur {
+            v[c] = true;
+        }
+        cur = cur.max(h);
+    }
+}
+
+fn count(h: u8, iter: impl Iterator<Item = u8>) -> usize {
+    let mut nb = 0;
+    for (i, cur_h) in iter.enumerate() {
+        if cur_h >= h {
+            return i + 1;
+        }
+        nb = i + 1;
+    }
+    nb
+}
+
+/// The trees visible from outside the grid.
+fn visible(f: &Grid<u8>) -> Grid<bool> {
+    let (w, h) = (f.width(), f.height());
+    let mut v = Grid::new(w, h, false);
+    for i in 0..h {
+        look(f, &mut v, f.ray((i, 0), (0, 1)));
+        look(f, &mut v, f.ray((i, w - 1), (0, -1)));
+    }
+    for j in 0..w {
+        look(f, &mut v, f.ray((0, j), (1, 0)));
+        look(f, &mut v, f.ray((h - 1, j), (-1, 0)));
+    }
+    v
+}
+
+/// The visible trees, in black.
+pub fn image(f: &Grid<u8>) -> Image {
+    Image::Bitmap(visible(f))
+}
+
+pub struct Day08;
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Grid<u8>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let trees = Grid::parse(parse::lines(input), "a tree height, from `0` to `9`", |c| {
+            c.is_ascii_digit().then_some(c as u8)
+        })?;
+        Ok(trees)
+    }
+    fn validate(_: &str, trees: &Self::Input) -> Vec<String> {
+        let (w, h) = (trees.width(), trees.height());
+        match w == h {
+            true => vec![],
+            false => vec![format!("a square grid expected, got {}x{} trees", h, w)],
+        }
+    }
+    fn part1(f: &Self::Input) -> anyhow::Result<Answer> {
+        let v = visible(f);
+        Ok(v.cells().iter().filter(|v| **v).count().into())
+    }
+    fn part2(f: &Self::Input) -> anyhow::Result<Answer> {
+        let max = f.coords().map(|c| {
+            DIRS4
+                .into_iter()
+                .map(|d| count(f[c], f.ray(c, d).skip(1).map(|c| f[c])))
+                .product::<usize>()
+        });
+        Ok(max.max().unwrap_or(0).into())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::parse;
+use crate::solution::{Answer, Solution, Streaming};
+use crate::trace;
+use std::cmp::Ordering::*;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+/// A rope, with the positions visited by its tail.
+struct Rope {
+    knots: Vec<(i32, i32)>,
+    visited: HashSet<(i32, i32)>,
+}
+impl Rope {
+    fn new(nb_knots: usize) -> Self {
+        Self {
+            knots: vec![(0, 0); nb_knots],
+            visited: HashSet::new(),
+        }
+    }
+    fn step(&mut self, m: u8) {
+        let r = &mut self.knots;
+        match m {
+            b'U' => r[0].1 += 1,
+            b'D' => r[0].1 -= 1,
+            b'L' => r[0].0 -= 1,
+            b'R' => r[0].0 += 1,
+            _ => unreachable!(),
+        }
+        for i in 0..r.len() - 1 {
+            let (h, t) = (r[i], &mut r[i + 1]);
+            if (h.0 - 1..=h.0 + 1).contains(&t.0) && (h.1 - 1..=h.1 + 1).contains(&t.1) {
+                continue;
+            }
+            match h.0.cmp(&t.0) {
+                Less => t.0 -= 1,
+                Greater => t.0 += 1,
+                Equal => (),
+            }
+            match h.1.cmp(&t.1) {
+                Less => t.1 -= 1,
+                Greater => t.1 += 1,
+                Equal => (),
+            }
+        }
+        let knots = || {
+            r.iter()
+                .map(|(x, y)| format!("{},{}", x, y))
+                .collect::<Vec<_>>()
+        };
+        trace!(
+            9,
+            "step",
+            knots = r.len(),
+            dir = m as char,
+            positions = knots().join(" ")
+        );
+        self.visited.insert(r[r.len() - 1]);
+    }
+}
+
+pub struct Day09;
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const STREAMING: Streaming = Streaming::SinglePass;
+    /// The numbers of positions visited by the tails of the ropes of 2
+    /// and 10 knots, moved while reading the motions of the head.
+    type Input = [usize; 2];
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_reader(&mut input.as_bytes())
+    }
+    fn parse_reader(input: &mut dyn BufRead) -> anyhow::Result<Self::Input> {
+        let mut ropes = [Rope::new(2), Rope::new(10)];
+        parse::read_lines(input, |l| {
+            let (m, nb) = match *l.text.split(' ').collect::<Vec<_>>() {
+                [m @ ("U" | "D" | "L" | "R"), nb] => {
+                    (m.as_bytes()[0], l.parse::<u32>(nb, "a number of steps")?)
+                }
+                [m, _] => return Err(l.error(m, "`U`, `D`, `L` or `R`").into()),
+                _ => {
+                    let e = l.error_line("a direction and a number of steps, as `R 4`");
+                    return Err(e.into());
+                }
+            };
+            for rope in &mut ropes {
+                for _ in 0..nb {
+                    rope.step(m);
+                }
+            }
+            Ok(())
+        })?;
+        Ok(ropes.map(|r| r.visited.len()))
+    }
+    fn part1(visited: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(visited[0].into())
+    }
+    fn part2(visited: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(visited[1].into())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::params::List;
+use crate::parse;
+use crate::solution::{Answer, Solution, Streaming};
+use std::io::BufRead;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Instr {
+    Noop,
+    Addx(i32),
+}
+
+crate::params! {
+    /// The constants of the puzzle.
+    pub struct Params {
+        /// The cycles during which the signal strength is summed.
+        pub cycles: List<i32> = List(vec![20, 60, 100, 140, 180, 220]),
+    }
+}
+
+fn strength(cycles: &[i32], cycle: i32, x: i32) -> i32 {
+    if cycles.contains(&cycle) {
+        cycle * x
+    } else {
+        0
+    }
+}
+
+/// The number of pixels of the CRT, 6 rows of 40.
+const NB_PIXELS: usize = 240;
+
+/// The device, with the signal strengths and the screen kept while
+/// running the instructions.
+#[derive(Debug, Clone)]
+pub struct Device {
+    /// The number of cycles run.
+    cycle: i32,
+    x: i32,
+    /// The sum of the sampled signal strengths.
+    strengths: i32,
+    /// The pixels of the CRT, drawn again every 240 cycles.
+    crt: [bool; NB_PIXELS],
+}
+impl Device {
+    fn new() -> Self {
+        Self {
+            cycle: 0,
+            x: 1,
+            strengths: 0,
+            crt: [false; NB_PIXELS],
+        }
+    }
+    /// Run an instruction, sampling the signal strength during the
+    /// `cycles`.
+    fn run(&mut self, instr: Instr, cycles: &[i32]) {
+        let nb_cycles = match instr {
+            Instr::Noop => 1,
+            Instr::Addx(_) => 2,
+        };
+        for _ in 0..nb_cycles {
+            self.cycle += 1;
+            self.strengths += strength(cycles, self.cycle, self.x);
+            let pixel = (self.cycle - 1) as usize % NB_PIXELS;
+            self.crt[pixel] = (pixel as i32 % 40 - self.x).abs() <= 1;
+        }
+        if let Instr::Addx(nb) = instr {
+            self.x += nb;
+        }
+    }
+    /// The lines of the screen, as far as drawn.
+    fn screen(&self) -> String {
+        let mut screen = String::new();
+        let nb = (self.cycle as usize).min(NB_PIXELS);
+        for (pos, &lit) in self.crt[..nb].iter().enumerate() {
+            screen.push(if lit { '#' } else { ' ' });
+            if pos % 40 == 39 {
+                screen.push('\n');
+            }
+        }
+        screen
+    }
+}
+
+pub struct Day10;
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const STREAMING: Streaming = Streaming::SinglePass;
+    /// The device, after running the instructions while reading them.
+    type Input = Device;
+    type Params = Params;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_reader(&mut input.as_bytes())
+    }
+    fn parse_with(input: &str, params: &Params) -> anyhow::Result<Self::Input> {
+        Self::parse_reader_with(&mut input.as_bytes(), params)
+    }
+    fn parse_reader(input: &mut dyn BufRead) -> anyhow::Result<Self::Input> {
+        Self::parse_reader_with(input, &Params::default())
+    }
+    fn parse_reader_with(input: &mut dyn BufRead, params: &Params) -> anyhow::Result<Self::Input> {
+        let mut device = Device::new();
+        parse::read_lines(input, |l| {
+            let instr = match *l.text.split(' ').collect::<Vec<_>>() {
+                ["noop"] => Instr::Noop,
+                ["addx", nb] => Instr::Addx(l.parse(nb, "a number")?),
+                _ => return Err(l.error_line("`noop` or `addx N`").into()),
+            };
+            device.run(instr, &params.cycles.0);
+            Ok(())
+        })?;
+        Ok(device)
+    }
+    fn part1(device: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(device.strengths.into())
+    }
+    fn part2(device: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(device.screen().into())
+    }
+}
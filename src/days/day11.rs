@@ -0,0 +1,207 @@
+use crate::parse::{self, Group, Line, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use std::sync::Arc;
+
+type Score = u64;
+type Operation = Arc<dyn Fn(Score) -> Score>;
+
+/// The number `nb` of a monkey to throw to, among `nb_monkeys` ones.
+fn parse_monkey(l: &Line, nb: &str, nb_monkeys: usize) -> Result<usize, ParseError> {
+    match l.parse(nb, "a monkey number")? {
+        to if to < nb_monkeys => Ok(to),
+        _ => Err(l.error(
+            nb,
+            format!(
+                "a monkey number, from 0 to {}",
+                nb_monkeys.saturating_sub(1)
+            ),
+        )),
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<Score>,
+    operation: Operation,
+    test: Score,
+    if_true: usize,
+    if_false: usize,
+    nb_inspections: usize,
+}
+impl Monkey {
+    /// Parse a monkey, among `nb_monkeys` ones.
+    fn try_new(group: &Group, nb_monkeys: usize) -> Result<Self, ParseError> {
+        let header = group.lines[0];
+        if !matches!(*header.text.split(' ').collect::<Vec<_>>(), ["Monkey", _]) {
+            return Err(header.error_line("`Monkey N:`"));
+        }
+        let missing = |what: &str| header.error_line(format!("{} for this monkey", what));
+        let mut items = Err(missing("a `Starting items:` line"));
+        let mut operation = Err::<Operation, _>(missing("an `Operation:` line"));
+        let mut test = Err(missing("a `Test:` line"));
+        let mut if_true = Err(missing("an `If true:` line"));
+        let mut if_false = Err(missing("an `If false:` line"));
+        for l in &group.lines[1..] {
+            match *l
+                .text
+                .split(' ')
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+            {
+                ["Starting", "items:", ref v @ ..] => {
+                    items = Ok(v
+                        .iter()
+                        .map(|s| l.parse::<Score>(s.trim_end_matches(','), "a worry level"))
+                        .collect::<Result<_, _>>()?);
+                }
+                ["Operation:", "new", "=", "old", "*", "old"] => {
+                    operation = Ok(Arc::new(move |i| i * i));
+                }
+                ["Operation:", "new", "=", "old", "*", nb] => {
+                    let nb = l.parse::<Score>(nb, "a number or `old`")?;
+                    operation = Ok(Arc::new(move |i| i * nb));
+                }
+                ["Operation:", "new", "=", "old", "+", nb] => {
+                    let nb = l.parse::<Score>(nb, "a number")?;
+                    operation = Ok(Arc::new(move |i| i + nb));
+                }
+                ["Test:", "divisible", "by", nb] => match l.parse(nb, "a divisor")? {
+                    0 => return Err(l.error(nb, "a non zero divisor")),
+                    nb => test = Ok(nb),
+                },
+                ["If", "true:", "throw", "to", "monkey", nb] => {
+                    if_true = Ok(parse_monkey(l, nb, nb_monkeys)?)
+                }
+                ["If", "false:", "throw", "to", "monkey", nb] => {
+                    if_false = Ok(parse_monkey(l, nb, nb_monkeys)?)
+                }
+                ["Operation:", ..] => {
+                    return Err(l.error_line("`Operation: new = old * N`, `old * old` or `old + N`"))
+                }
+                _ => {
+                    return Err(l.error_line(
+                        "`Starting items:`, `Operation:`, `Test:`, `If true:` or `If false:`",
+                    ))
+                }
+            }
+        }
+        Ok(Self {
+            items: items?,
+            operation: operation?,
+            test: test?,
+            if_true: if_true?,
+            if_false: if_false?,
+            nb_inspections: 0,
+        })
+    }
+
+    fn turn(&mut self, manage: impl Fn(Score) -> Score) -> Vec<(usize, Score)> {
+        let v: Vec<_> = self
+            .items
+            .drain(..)
+            .map(|i| {
+                let i = manage((self.operation)(i));
+                let to_send = if i.is_multiple_of(self.test) {
+                    self.if_true
+                } else {
+                    self.if_false
+                };
+                (to_send, i)
+            })
+            .collect();
+        self.nb_inspections += v.len();
+        v
+    }
+}
+
+fn play(mut monkeys: Vec<Monkey>, nb: u32, manage: impl Fn(Score) -> Score) -> usize {
+    for round in 1..=nb {
+        for from in 0..monkeys.len() {
+            let thrown = monkeys[from].turn(&manage);
+            // checked once per turn, keeping the hot loop untouched
+            if trace::enabled() {
+                for &(to, worry) in &thrown {
+                    trace!(
+                        11,
+                        "throw",
+                        round = round,
+                        from = from,
+                        to = to,
+                        worry = worry
+                    );
+                }
+            }
+            for (to, i) in thrown {
+                monkeys[to].items.push(i);
+            }
+        }
+    }
+    monkeys.sort_by_key(|m| m.nb_inspections);
+    monkeys
+        .iter()
+        .rev()
+        .take(2)
+        .map(|m| m.nb_inspections)
+        .product()
+}
+
+/// Naive solutions, to check the real ones.
+pub mod naive {
+    use super::{Monkey, Score};
+
+    /// The second part, without the common multiple of the divisors.
+    ///
+    /// The worry levels grow too fast to be kept as is, so an item is
+    /// its worry levels modulo each divisor: as the operations are
+    /// additions and multiplications, it is enough to know where it
+    /// is thrown.
+    pub fn part2(monkeys: &[Monkey]) -> usize {
+        let divisors: Vec<Score> = monkeys.iter().map(|m| m.test).collect();
+        let mut items: Vec<Vec<Vec<Score>>> = monkeys
+            .iter()
+            .map(|m| {
+                let modulos = |&i: &Score| divisors.iter().map(|d| i % d).collect();
+                m.items.iter().map(modulos).collect()
+            })
+            .collect();
+        let mut nb_inspections = vec![0; monkeys.len()];
+        for _ in 0..10000 {
+            for (i, m) in monkeys.iter().enumerate() {
+                for item in std::mem::take(&mut items[i]) {
+                    nb_inspections[i] += 1;
+                    let item: Vec<_> = item
+                        .iter()
+                        .zip(&divisors)
+                        .map(|(&w, d)| (m.operation)(w) % d)
+                        .collect();
+                    let to = if item[i] == 0 { m.if_true } else { m.if_false };
+                    items[to].push(item);
+                }
+            }
+        }
+        nb_inspections.sort_unstable();
+        nb_inspections.iter().rev().take(2).product()
+    }
+}
+
+pub struct Day11;
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let nb_monkeys = parse::groups(input).count();
+        Ok(parse::parse_groups(input, |g| {
+            Monkey::try_new(g, nb_monkeys)
+        })?)
+    }
+    fn part1(monkeys: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(play(monkeys.clone(), 20, |s| s / 3).into())
+    }
+    fn part2(monkeys: &Self::Input) -> anyhow::Result<Answer> {
+        // We need a common multiple, the naive one is enough with u64
+        let cm: Score = monkeys.iter().map(|m| m.test).product();
+        Ok(play(monkeys.clone(), 10000, |s| s % cm).into())
+    }
+}
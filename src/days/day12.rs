@@ -0,0 +1,85 @@
+use crate::grid::{Coord, Grid};
+use crate::image::{heat, Image, BLACK, WHITE};
+use crate::parse;
+use crate::search::{self, Paths};
+use crate::solution::{Answer, Solution};
+use anyhow::Context;
+
+/// The shortest path from the target, going backward, to the first
+/// position matching `is_goal`.
+fn search(h: &HeightMap, is_goal: impl FnMut(&Coord) -> bool) -> Paths<Coord, usize> {
+    let map = &h.map;
+    let prev = |&c: &Coord| map.neighbours4(c).filter(move |&n| map[n] + 1 >= map[c]);
+    search::bfs([h.target], prev, is_goal)
+}
+
+pub struct HeightMap {
+    map: Grid<u8>,
+    source: Coord,
+    target: Coord,
+}
+
+/// The distances to the target, from blue for the nearest positions to
+/// red for the farthest, the unreachable ones being black, with the
+/// path of the part in white.
+pub fn image(h: &HeightMap, part: u8) -> anyhow::Result<Image> {
+    let all = search(h, |_| false);
+    let max = all.costs.values().copied().max().unwrap_or(0).max(1);
+    let mut image = h.map.map(|_| BLACK);
+    for (&c, &d) in &all.costs {
+        image[c] = heat(d as f64 / max as f64);
+    }
+    let paths = match part {
+        1 => search(h, |&c| c == h.source),
+        _ => search(h, |&c| h.map[c] == b'a'),
+    };
+    let goal = paths.goal.as_ref().context("no path")?;
+    for c in paths.path(goal).expect("the goal is reached") {
+        image[c] = WHITE;
+    }
+    Ok(Image::Pixmap(image))
+}
+
+pub struct Day12;
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = HeightMap;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let expected = "an elevation, from `a` to `z`, `S` or `E`";
+        let mut map = Grid::parse(parse::lines(input), expected, |c| {
+            matches!(c, 'a'..='z' | 'S' | 'E').then_some(c as u8)
+        })?;
+        let source = map
+            .position(|&c| c == b'S')
+            .ok_or_else(|| parse::eof(input, "a start position `S` in the map"))?;
+        let target = map
+            .position(|&c| c == b'E')
+            .ok_or_else(|| parse::eof(input, "a best signal position `E` in the map"))?;
+        map[source] = b'a';
+        map[target] = b'z';
+        Ok(HeightMap {
+            map,
+            source,
+            target,
+        })
+    }
+    fn validate(input: &str, _: &Self::Input) -> Vec<String> {
+        let mut res = vec![];
+        for (c, what) in [('S', "start position"), ('E', "best signal position")] {
+            match input.matches(c).count() {
+                1 => (),
+                nb => res.push(format!("exactly one {} `{}` expected, got {}", what, c, nb)),
+            }
+        }
+        res
+    }
+    fn part1(h: &Self::Input) -> anyhow::Result<Answer> {
+        let paths = search(h, |&c| c == h.source);
+        Ok(paths.goal_cost().context("no path to the source")?.into())
+    }
+    fn part2(h: &Self::Input) -> anyhow::Result<Answer> {
+        let paths = search(h, |&c| h.map[c] == b'a');
+        Ok(paths.goal_cost().context("no path to an `a`")?.into())
+    }
+}
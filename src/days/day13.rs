@@ -0,0 +1,70 @@
+use crate::parse::{self, Line, ParseError};
+use crate::solution::{Answer, Solution};
+use serde::Deserialize;
+use std::cmp::Ordering;
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Msg {
+    Int(u32),
+    Vec(Vec<Msg>),
+}
+impl Msg {
+    fn parse(l: Line) -> Result<Self, ParseError> {
+        serde_json::from_str(l.text).map_err(|e| {
+            let col = e.column().max(1);
+            l.error_at(col - 1..col, "a packet, as `[1,[2,3]]`")
+        })
+    }
+}
+impl PartialOrd for Msg {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Msg {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Msg::Int(l), Msg::Int(r)) => l.cmp(r),
+            (Msg::Vec(l), Msg::Vec(r)) => l.cmp(r),
+            (Msg::Vec(l), Msg::Int(_)) => l.as_slice().cmp(std::slice::from_ref(other)),
+            (Msg::Int(_), Msg::Vec(r)) => std::slice::from_ref(self).cmp(r),
+        }
+    }
+}
+
+pub struct Day13;
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    /// The messages, by pairs.
+    type Input = Vec<Msg>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let pairs = parse::parse_groups(input, |g| match g.lines[..] {
+            [l, r] => Ok([Msg::parse(l)?, Msg::parse(r)?]),
+            [_, _, e, ..] => Err(e.error_line("an empty line between the pairs")),
+            _ => Err(g.error_after("the second packet of the pair")),
+        })?;
+        Ok(pairs.into_iter().flatten().collect())
+    }
+    fn part1(msgs: &Self::Input) -> anyhow::Result<Answer> {
+        let score: usize = msgs
+            .chunks(2)
+            .zip(1..)
+            .filter(|(p, _)| p[0] <= p[1])
+            .map(|(_, i)| i)
+            .sum();
+        Ok(score.into())
+    }
+    fn part2(msgs: &Self::Input) -> anyhow::Result<Answer> {
+        let first: Msg = serde_json::from_str("[[2]]")?;
+        let second: Msg = serde_json::from_str("[[6]]")?;
+        let mut msgs = msgs.clone();
+        msgs.extend([first.clone(), second.clone()]);
+
+        msgs.sort_unstable();
+        let f_pos = msgs.iter().position(|m| m == &first).unwrap() + 1;
+        let s_pos = msgs.iter().position(|m| m == &second).unwrap() + 1;
+        Ok((f_pos * s_pos).into())
+    }
+}
@@ -0,0 +1,164 @@
+use crate::animate::{Frame, Show};
+use crate::grid::{Coord, Grid, Pos};
+use crate::image::Image;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+use std::ops::ControlFlow;
+
+/// Where the sand comes from, as `(y, x)`.
+const S_INIT: Pos = (0, 500);
+
+macro_rules! check {
+    ($is_air:ident, $s:ident, $n:expr) => {
+        let Some(&can) = $is_air.get($n) else {
+            break;
+        };
+        if can {
+            $s = $n;
+            continue;
+        }
+    };
+}
+
+/// Where a unit of sand coming from `s_init` comes to rest, `None` if
+/// it falls into the abyss.
+#[allow(clippy::while_let_loop)] // the loop is exited by `check!`
+fn drop_sand(is_air: &Grid<bool>, s_init: Coord) -> Option<Coord> {
+    let mut s = s_init;
+    loop {
+        check!(is_air, s, (s.0 + 1, s.1));
+        check!(is_air, s, (s.0 + 1, s.1.wrapping_sub(1)));
+        check!(is_air, s, (s.0 + 1, s.1 + 1));
+        return Some(s);
+    }
+    None
+}
+
+/// Let the sand fall until it stops, returning the number of units at
+/// rest.
+fn simulate(is_air: &mut Grid<bool>) -> usize {
+    let Some(s_init) = is_air.coord(S_INIT) else {
+        return 0;
+    };
+    let mut i = 0;
+    while let Some(s) = drop_sand(is_air, s_init) {
+        i += 1;
+        if s == s_init {
+            break;
+        }
+        is_air[s] = false;
+    }
+    i
+}
+
+/// The map with the floor of the second part.
+fn with_floor(is_air: &Grid<bool>) -> Grid<bool> {
+    let mut is_air = is_air.clone();
+    let y_floor = is_air.pos((is_air.height() - 1, 0)).0 + 2;
+    // The sand can't go further than a 45° slope from its source.
+    is_air.grow_to((y_floor, S_INIT.1 - y_floor), true);
+    is_air.grow_to((y_floor, S_INIT.1 + y_floor), true);
+    for x in 0..is_air.width() {
+        let floor = is_air.height() - 1;
+        is_air[(floor, x)] = false;
+    }
+    is_air
+}
+
+/// Show the map each time a unit of sand comes to rest.
+pub fn animate(is_air: &Grid<bool>, part: u8, show: &mut Show) -> ControlFlow<()> {
+    let mut is_air = match part {
+        1 => is_air.clone(),
+        _ => with_floor(is_air),
+    };
+    let Some(s_init) = is_air.coord(S_INIT) else {
+        return ControlFlow::Continue(());
+    };
+    let mut map = is_air.map(|&air| if air { '.' } else { '#' });
+    map[s_init] = '+';
+    let mut i = 0;
+    while let Some(s) = drop_sand(&is_air, s_init) {
+        i += 1;
+        is_air[s] = false;
+        map[s] = 'o';
+        show(Frame::new(format!("units of sand: {}", i), &map).focus(is_air.pos(s)))?;
+        if s == s_init {
+            break;
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+/// The rocks in grey and the sand in yellow, once it stopped, with its
+/// source in red.
+pub fn image(is_air: &Grid<bool>, part: u8) -> Image {
+    let rocks = match part {
+        1 => is_air.clone(),
+        _ => with_floor(is_air),
+    };
+    let mut sand = rocks.clone();
+    simulate(&mut sand);
+    let mut image = rocks.map(|_| [16, 16, 32]);
+    for (c, _) in rocks.iter().filter(|(_, &air)| !air) {
+        image[c] = [112, 112, 112];
+    }
+    for (c, _) in sand.iter().filter(|&(c, &air)| !air && rocks[c]) {
+        image[c] = [230, 190, 90];
+    }
+    if let Some(s) = rocks.coord(S_INIT) {
+        image[s] = [255, 0, 0];
+    }
+    Image::Pixmap(image)
+}
+
+pub struct Day14;
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    /// Is there air at `(y, x)`?
+    type Input = Grid<bool>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut paths = vec![];
+        for l in parse::lines(input) {
+            let coords = l
+                .text
+                .split(" -> ")
+                .map(|s| {
+                    let (x, y) = s
+                        .split_once(',')
+                        .ok_or_else(|| l.error(s, "a point, as `498,4`"))?;
+                    Ok((
+                        l.parse::<i64>(y, "a y coordinate")?,
+                        l.parse::<i64>(x, "a x coordinate")?,
+                    ))
+                })
+                .collect::<Result<Vec<Pos>, parse::ParseError>>()?;
+            paths.push(coords);
+        }
+        let points = paths.iter().flatten();
+        let min = points
+            .clone()
+            .fold(S_INIT, |m, p| (m.0.min(p.0), m.1.min(p.1)));
+        let max = points.fold(S_INIT, |m, p| (m.0.max(p.0), m.1.max(p.1)));
+        let mut is_air = Grid::new(1, 1, true).with_origin(S_INIT);
+        is_air.grow_to(min, true);
+        is_air.grow_to(max, true);
+        for coords in &paths {
+            for (&a, &b) in coords.iter().zip(&coords[1..]) {
+                for y in a.0.min(b.0)..=a.0.max(b.0) {
+                    for x in a.1.min(b.1)..=a.1.max(b.1) {
+                        let c = is_air.coord((y, x)).expect("grown to contain the paths");
+                        is_air[c] = false;
+                    }
+                }
+            }
+        }
+        Ok(is_air)
+    }
+    fn part1(is_air: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(simulate(&mut is_air.clone()).into())
+    }
+    fn part2(is_air: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(simulate(&mut with_floor(is_air)).into())
+    }
+}
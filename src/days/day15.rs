@@ -0,0 +1,120 @@
+use crate::parallel;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+
+lazy_static::lazy_static! {
+    static ref RE: Regex =
+        Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$").unwrap();
+}
+
+pub type C = i64;
+/// A sensor with its closest beacon.
+pub type Sensor = ((C, C), (C, C));
+type Range = std::ops::RangeInclusive<C>;
+crate::params! {
+    /// The sizes of the puzzle, smaller in the example.
+    pub struct Params {
+        /// The row where to count the positions without beacon.
+        pub y: C = 2_000_000,
+        /// The maximal coordinates of the distress beacon.
+        pub space: C = 4_000_000,
+    }
+}
+
+fn d(a: (C, C), b: (C, C)) -> C {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+fn substract(set: &mut Vec<Range>, s: Range) {
+    for r in std::mem::take(set) {
+        if r.start() < s.start() && s.start() <= r.end() {
+            set.push(*r.start()..=*s.start() - 1);
+        }
+        if r.start() <= s.end() && s.end() < r.end() {
+            set.push(*s.end() + 1..=*r.end());
+        }
+        if s.end() < r.start() || r.end() < s.start() {
+            set.push(r);
+        }
+    }
+}
+
+fn range_at(s: (C, C), b: (C, C), at: C) -> Range {
+    let b_dist = d(s, b);
+    let target_dist = d(s, (s.0, at));
+    let y_scanned = b_dist - target_dist;
+    s.0 - y_scanned..=s.0 + y_scanned
+}
+
+/// The first position, by row, not scanned by the sensors in the
+/// square from 0 to `space`.
+pub fn distress(sensors: &[Sensor], space: C) -> Option<(C, C)> {
+    // blocks of rows, for the threads
+    const ROWS: C = 4096;
+    let blocks: Vec<C> = (0..=space).step_by(ROWS as usize).collect();
+    parallel::find_map_first(&blocks, |&block| {
+        (block..=space.min(block + ROWS - 1)).find_map(|y| {
+            let mut unscanned = vec![0..=space];
+            for &(s, b) in sensors {
+                let r = range_at(s, b, y);
+                if !r.is_empty() {
+                    substract(&mut unscanned, r);
+                }
+            }
+            let x = unscanned.into_iter().flatten().next();
+            x.map(|x| (x, y))
+        })
+    })
+}
+
+/// Naive solutions, to check the real ones.
+pub mod naive {
+    use super::{d, Sensor, C};
+
+    /// [`distress`](super::distress), checking every position.
+    pub fn distress(sensors: &[Sensor], space: C) -> Option<(C, C)> {
+        let mut positions = (0..=space).flat_map(|y| (0..=space).map(move |x| (x, y)));
+        positions.find(|&p| sensors.iter().all(|&(s, b)| d(s, p) > d(s, b)))
+    }
+}
+
+pub struct Day15;
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    /// The sensors with their closest beacon.
+    type Input = (Vec<Sensor>, Params);
+    type Params = Params;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+    fn parse_with(input: &str, params: &Params) -> anyhow::Result<Self::Input> {
+        let sensors = parse::lines(input).map(|l| {
+            let c = l.captures(&RE, "Sensor at x=X, y=Y: closest beacon is at x=X, y=Y")?;
+            let coord = |i| l.parse(&c[i], "a coordinate");
+            Ok(((coord(1)?, coord(2)?), (coord(3)?, coord(4)?)))
+        });
+        let sensors = sensors.collect::<Result<_, parse::ParseError>>()?;
+        Ok((sensors, params.clone()))
+    }
+    fn part1((sensors, p): &Self::Input) -> anyhow::Result<Answer> {
+        let mut beacon = HashSet::new();
+        let mut scanned = HashSet::new();
+        for &(s, b) in sensors {
+            if b.1 == p.y {
+                beacon.insert(b.0);
+            }
+            for x in range_at(s, b, p.y) {
+                scanned.insert(x);
+            }
+        }
+        Ok((scanned.len() - beacon.len()).into())
+    }
+    fn part2((sensors, p): &Self::Input) -> anyhow::Result<Answer> {
+        match distress(sensors, p.space) {
+            Some((x, y)) => Ok((y + x * 4000000).into()),
+            None => anyhow::bail!("no distress beacon found"),
+        }
+    }
+}
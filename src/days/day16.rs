@@ -0,0 +1,191 @@
+use crate::parallel;
+use crate::pareto::{self, Frontier, Problem};
+use crate::parse;
+use crate::search;
+use crate::solution::{Answer, Solution};
+use regex::Regex;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU32, Ordering::Relaxed};
+
+lazy_static::lazy_static! {
+    static ref RE: Regex =
+        Regex::new(r"^Valve (..) has flow rate=(\d+); tunnels? leads? to valves? (.*)$").unwrap();
+}
+
+pub struct Room {
+    rate: u32,
+    tunnels: HashMap<String, u32>,
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct State {
+    remaining: u32,
+    releasing: u32,
+    openned: HashSet<String>,
+    position: String,
+}
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.remaining
+            .cmp(&other.remaining)
+            .then_with(|| self.releasing.cmp(&other.releasing))
+    }
+}
+
+struct Volcano<'a> {
+    rooms: &'a HashMap<String, Room>,
+    /// Prune with an upper bound, only when searching the best state.
+    bounded: bool,
+}
+impl Problem for Volcano<'_> {
+    type State = State;
+    type Key = String;
+    type Value = u32;
+
+    fn key(&self, state: &State) -> String {
+        state.position.clone()
+    }
+    fn successors(&self, state: &State) -> impl Iterator<Item = State> {
+        self.rooms[&state.position]
+            .tunnels
+            .iter()
+            .filter(|(r, _)| !state.openned.contains(*r))
+            .filter_map(|(room, &dist)| {
+                let remaining = state.remaining.checked_sub(dist + 1)?;
+                let mut openned = state.openned.clone();
+                openned.insert(room.clone());
+                Some(State {
+                    remaining,
+                    openned,
+                    releasing: state.releasing + self.rooms[room].rate * remaining,
+                    position: room.clone(),
+                })
+            })
+    }
+    fn objective(&self, state: &State) -> u32 {
+        state.releasing
+    }
+    fn dominates(&self, a: &State, b: &State) -> bool {
+        b.releasing <= a.releasing && b.openned.is_superset(&a.openned)
+    }
+    /// Opening the best valves first, each one taking at least 2
+    /// minutes to go there and open it.
+    fn upper_bound(&self, state: &State) -> Option<u32> {
+        if !self.bounded {
+            return None;
+        }
+        let mut rates: Vec<_> = self
+            .rooms
+            .iter()
+            .filter(|(r, _)| !state.openned.contains(*r))
+            .map(|(_, room)| room.rate)
+            .collect();
+        rates.sort_unstable_by_key(|&r| Reverse(r));
+        let times = (1..).map_while(|k| state.remaining.checked_sub(2 * k));
+        Some(state.releasing + rates.iter().zip(times).map(|(r, t)| r * t).sum::<u32>())
+    }
+}
+
+fn start(remaining: u32) -> State {
+    State {
+        remaining,
+        releasing: 0,
+        openned: Default::default(),
+        position: "AA".into(),
+    }
+}
+
+fn best_at_2(volcano: &Volcano, states: impl Iterator<Item = State>) -> u32 {
+    let mut solutions = Frontier::default();
+    for state in states {
+        solutions.insert(volcano, (), state);
+    }
+    let mut solutions: Vec<_> = solutions.into_states().collect();
+    solutions.sort_unstable_by_key(|s| Reverse(s.releasing));
+    // the best pair found so far, shared by the threads
+    let releasing = AtomicU32::new(0);
+    let indices: Vec<_> = (0..solutions.len()).collect();
+    parallel::for_each(&indices, |&i| {
+        let s1 = &solutions[i];
+        for s2 in &solutions[i + 1..] {
+            if s1.releasing + s2.releasing <= releasing.load(Relaxed) {
+                break; // sorted, no better pair with `s1`
+            }
+            if s1.openned.is_disjoint(&s2.openned) {
+                releasing.fetch_max(s1.releasing + s2.releasing, Relaxed);
+            }
+        }
+    });
+    releasing.into_inner()
+}
+
+/// Keep only the valves with a flow, and `AA`, with the distances
+/// between them as tunnels.
+fn simplify(volcano: &mut HashMap<String, Room>) {
+    let dists = search::all_pairs(volcano.keys().cloned(), |r: &String| {
+        volcano[r].tunnels.iter().map(|(t, &d)| (t.clone(), d))
+    });
+    let targets: Vec<_> = volcano
+        .iter()
+        .filter(|(_, v)| v.rate > 0)
+        .map(|(k, _)| k.clone())
+        .collect();
+    volcano.retain(|k, _| targets.contains(k) || k == "AA");
+    for (from, room) in volcano.iter_mut() {
+        room.tunnels = targets
+            .iter()
+            .filter(|&to| to != from)
+            .filter_map(|to| Some((to.clone(), dists.cost(from, to)?)))
+            .collect();
+    }
+}
+
+pub struct Day16;
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    /// The simplified volcano, with only the useful valves.
+    type Input = HashMap<String, Room>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut volcano = HashMap::new();
+        for l in parse::lines(input) {
+            let c = l.captures(
+                &RE,
+                "Valve XX has flow rate=N; tunnels lead to valves XX, YY",
+            )?;
+            volcano.insert(
+                c[1].into(),
+                Room {
+                    rate: l.parse(&c[2], "a flow rate")?,
+                    tunnels: c[3].split(", ").map(|t| (t.into(), 1)).collect(),
+                },
+            );
+        }
+        if !volcano.contains_key("AA") {
+            return Err(parse::eof(input, "a valve `AA`").into());
+        }
+        simplify(&mut volcano);
+        Ok(volcano)
+    }
+    fn part1(rooms: &Self::Input) -> anyhow::Result<Answer> {
+        let volcano = Volcano {
+            rooms,
+            bounded: true,
+        };
+        Ok(pareto::search(&volcano, start(30)).best.releasing.into())
+    }
+    fn part2(rooms: &Self::Input) -> anyhow::Result<Answer> {
+        let volcano = Volcano {
+            rooms,
+            bounded: false,
+        };
+        let states = pareto::search(&volcano, start(26)).frontier.into_states();
+        Ok(best_at_2(&volcano, states).into())
+    }
+}
@@ -0,0 +1,243 @@
+use crate::animate::{Frame, Show};
+use crate::cycle::{self, Method};
+use crate::grid::Grid;
+use crate::parse::{self, Line};
+use crate::solution::{Answer, Solution};
+use std::ops::ControlFlow;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Rock([u16; 4]);
+impl Rock {
+    fn manage_jet(&mut self, jet: u8, cave: &[u16]) {
+        let mut new = *self;
+        let shift = match jet {
+            b'<' => |x| x << 1,
+            b'>' => |x| x >> 1,
+            _ => panic!("bad move {:?}", jet as char),
+        };
+        for l in &mut new.0 {
+            *l = shift(*l);
+        }
+        if new.touch_something(cave) {
+            return;
+        }
+        *self = new;
+    }
+    fn touch_something(&self, cave: &[u16]) -> bool {
+        cave.iter().zip(&self.0).any(|(c, r)| c & r != 0)
+    }
+    fn stabilize(&self, cave: &mut [u16]) {
+        for (c, r) in cave.iter_mut().zip(&self.0) {
+            *c |= *r;
+        }
+    }
+}
+
+static ROCKS: &[Rock] = &[
+    Rock([
+        0b0001_1110_0000_0000,
+        0b0000_0000_0000_0000,
+        0b0000_0000_0000_0000,
+        0b0000_0000_0000_0000,
+    ]),
+    Rock([
+        0b0000_1000_0000_0000,
+        0b0001_1100_0000_0000,
+        0b0000_1000_0000_0000,
+        0b0000_0000_0000_0000,
+    ]),
+    Rock([
+        0b0001_1100_0000_0000,
+        0b0000_0100_0000_0000,
+        0b0000_0100_0000_0000,
+        0b0000_0000_0000_0000,
+    ]),
+    Rock([
+        0b0001_0000_0000_0000,
+        0b0001_0000_0000_0000,
+        0b0001_0000_0000_0000,
+        0b0001_0000_0000_0000,
+    ]),
+    Rock([
+        0b0001_1000_0000_0000,
+        0b0001_1000_0000_0000,
+        0b0000_0000_0000_0000,
+        0b0000_0000_0000_0000,
+    ]),
+];
+const CAVE_LAYER: u16 = 0b1000_0000_1111_1111;
+
+/// The number of rows at the top of the tower to compare the states.
+const TOP_ROWS: usize = 64;
+
+#[derive(Clone)]
+struct Context<'a> {
+    jets: &'a [u8],
+    /// The index of the next jet.
+    jet: usize,
+    cur_rock_height: usize,
+    cur_rock: Rock,
+    cave: Vec<u16>,
+    nb_fallen: usize,
+}
+impl<'a> Context<'a> {
+    fn new(jets: &'a [u8]) -> Self {
+        let mut cave = vec![CAVE_LAYER; 8];
+        cave[0] = 0b1111_1111_1111_1111;
+        Self {
+            jets,
+            jet: 0,
+            cur_rock: ROCKS[0],
+            cur_rock_height: 4,
+            cave,
+            nb_fallen: 0,
+        }
+    }
+    fn step(&mut self) {
+        let jet = self.jets[self.jet];
+        self.jet = (self.jet + 1) % self.jets.len();
+        self.cur_rock
+            .manage_jet(jet, &self.cave[self.cur_rock_height..]);
+
+        if self
+            .cur_rock
+            .touch_something(&self.cave[self.cur_rock_height - 1..])
+        {
+            self.cur_rock
+                .stabilize(&mut self.cave[self.cur_rock_height..]);
+
+            self.nb_fallen += 1;
+
+            self.cur_rock = ROCKS[self.nb_fallen % ROCKS.len()];
+            let height = self.top();
+            self.cur_rock_height = height + 3;
+            self.cave.resize(self.cur_rock_height + 4, CAVE_LAYER);
+        } else {
+            self.cur_rock_height -= 1;
+        }
+    }
+    /// Let the current rock fall until it stops.
+    fn drop_rock(&mut self) {
+        let nb_fallen = self.nb_fallen;
+        while self.nb_fallen == nb_fallen {
+            self.step();
+        }
+    }
+    /// The index of the first empty layer.
+    fn top(&self) -> usize {
+        self.cave
+            .iter()
+            .position(|&c| c == CAVE_LAYER)
+            .unwrap_or(self.cave.len())
+    }
+    fn height(&self) -> usize {
+        self.top() - 1
+    }
+    /// The cave with the falling rock, the layer `n` being at the row
+    /// `-n`.
+    fn grid(&self) -> Grid<char> {
+        let top = self.cave.len();
+        Grid::from_fn(9, top, |(i, j)| {
+            let layer = top - 1 - i;
+            let bit = 1 << (15 - j);
+            let rock = layer
+                .checked_sub(self.cur_rock_height)
+                .and_then(|k| self.cur_rock.0.get(k));
+            match (layer, j) {
+                (0, 0 | 8) => '+',
+                (0, _) => '-',
+                (_, 0 | 8) => '|',
+                _ if self.cave[layer] & bit != 0 => '#',
+                _ if rock.is_some_and(|r| r & bit != 0) => '@',
+                _ => '.',
+            }
+        })
+        .with_origin((1 - top as i64, 0))
+    }
+    /// The top of the tower, with the next rock and jet. The rows below
+    /// are supposed to be unreachable.
+    fn state(&self) -> (Vec<u16>, usize, usize) {
+        let top = self.top();
+        let rows = self.cave[top.saturating_sub(TOP_ROWS)..top].to_vec();
+        (rows, self.nb_fallen % ROCKS.len(), self.jet)
+    }
+}
+
+/// The height of the tower after `nb` rocks, skipping the cycles.
+pub fn height(jets: &[u8], nb: usize) -> i64 {
+    let height = cycle::extrapolate(
+        Method::Brent,
+        &Context::new(jets),
+        Context::drop_rock,
+        Context::state,
+        |c| c.height() as i64,
+        nb,
+    );
+    height.value
+}
+
+/// Show the cave at each step of a part, until its number of rocks
+/// has fallen.
+pub fn animate((jets, p): &(Vec<u8>, Params), part: u8, show: &mut Show) -> ControlFlow<()> {
+    let nb_rocks = if part == 1 { p.rocks1 } else { p.rocks2 };
+    let mut c = Context::new(jets);
+    while c.nb_fallen < nb_rocks {
+        let caption = format!("rock {}, height {}", c.nb_fallen + 1, c.height());
+        let focus = (-(c.cur_rock_height as i64) - 1, 4);
+        show(Frame::new(caption, &c.grid()).focus(focus))?;
+        c.step();
+    }
+    ControlFlow::Continue(())
+}
+
+/// Naive solutions, to check the real ones.
+pub mod naive {
+    use super::Context;
+
+    /// [`height`](super::height), dropping every rock.
+    pub fn height(jets: &[u8], nb: usize) -> i64 {
+        let mut c = Context::new(jets);
+        for _ in 0..nb {
+            c.drop_rock();
+        }
+        c.height() as i64
+    }
+}
+
+crate::params! {
+    /// The numbers of rocks of the puzzle.
+    pub struct Params {
+        /// The number of fallen rocks of the first part.
+        pub rocks1: usize = 2022,
+        /// The number of fallen rocks of the second part.
+        pub rocks2: usize = 1_000_000_000_000,
+    }
+}
+
+pub struct Day17;
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    /// The jets, `<` or `>`.
+    type Input = (Vec<u8>, Params);
+    type Params = Params;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+    fn parse_with(input: &str, params: &Params) -> anyhow::Result<Self::Input> {
+        let text = input.trim_end();
+        if text.is_empty() {
+            return Err(parse::eof(input, "a jet").into());
+        }
+        let l = Line { no: 1, text };
+        if let Some(i) = text.find(|c| c != '<' && c != '>') {
+            return Err(l.error_at(i..i + 1, "`<` or `>`").into());
+        }
+        Ok((text.as_bytes().to_vec(), params.clone()))
+    }
+    fn part1((jets, p): &Self::Input) -> anyhow::Result<Answer> {
+        Ok(naive::height(jets, p.rocks1).into())
+    }
+    fn part2((jets, p): &Self::Input) -> anyhow::Result<Answer> {
+        Ok(height(jets, p.rocks2).into())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::grid::Grid;
+use crate::image::{Image, BLACK, WHITE};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+type Cube = [i8; 3];
+fn next(min: Cube, max: Cube, cur: Cube) -> impl Iterator<Item = Cube> {
+    [
+        [cur[0] + 1, cur[1], cur[2]],
+        [cur[0] - 1, cur[1], cur[2]],
+        [cur[0], cur[1] + 1, cur[2]],
+        [cur[0], cur[1] - 1, cur[2]],
+        [cur[0], cur[1], cur[2] + 1],
+        [cur[0], cur[1], cur[2] - 1],
+    ]
+    .into_iter()
+    .filter(move |c| c.iter().zip(min).all(|(&c, m)| m <= c))
+    .filter(move |c| c.iter().zip(max).all(|(&c, m)| c <= m))
+}
+
+/// The bounds of the cubes, with a layer of air around.
+fn bounds(cubes: &HashSet<Cube>) -> (Cube, Cube) {
+    let mut min = *cubes.iter().next().unwrap_or(&[0, 0, 0]);
+    let mut max = min;
+    for &c in cubes {
+        for i in 0..3 {
+            min[i] = min[i].min(c[i] - 1);
+            max[i] = max[i].max(c[i] + 1);
+        }
+    }
+    (min, max)
+}
+
+/// The air outside of the droplet, within the bounds, and the number
+/// of faces it touches.
+fn flood(cubes: &HashSet<Cube>, min: Cube, max: Cube) -> (HashSet<Cube>, usize) {
+    let mut visited = HashSet::from([min]);
+    let mut q = vec![min];
+    let mut nb_faces = 0;
+    while let Some(cur) = q.pop() {
+        for c in next(min, max, cur) {
+            if visited.contains(&c) {
+                continue;
+            }
+            if cubes.contains(&c) {
+                nb_faces += 1;
+            } else {
+                visited.insert(c);
+                q.push(c);
+            }
+        }
+    }
+    (visited, nb_faces)
+}
+
+/// The cross-sections of the droplet, one per `z` from left to right:
+/// the lava in orange, the air outside in blue and the air trapped
+/// inside in black.
+pub fn image(cubes: &HashSet<Cube>) -> Image {
+    let (min, max) = bounds(cubes);
+    let (outside, _) = flood(cubes, min, max);
+    let size = |i: usize| (max[i] - min[i] + 1) as usize;
+    let (w, h, d) = (size(0), size(1), size(2));
+    // a column of white between the sections
+    let image = Grid::from_fn(d * (w + 1) - 1, h, |(y, x)| {
+        let (z, x) = (x / (w + 1), x % (w + 1));
+        let c = [x, y, z].map(|i| i as i8);
+        let c = [min[0] + c[0], min[1] + c[1], min[2] + c[2]];
+        if x == w {
+            WHITE
+        } else if cubes.contains(&c) {
+            [230, 110, 20]
+        } else if outside.contains(&c) {
+            [120, 170, 230]
+        } else {
+            BLACK
+        }
+    });
+    Image::Pixmap(image)
+}
+
+pub struct Day18;
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = HashSet<Cube>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut cubes = HashSet::<Cube>::new();
+        for l in parse::lines(input) {
+            let coords = l
+                .text
+                .split(',')
+                .map(|c| l.parse(c, "a coordinate"))
+                .collect::<Result<Vec<_>, _>>()?;
+            let Ok(cube) = coords.as_slice().try_into() else {
+                return Err(l.error_line("`X,Y,Z`").into());
+            };
+            cubes.insert(cube);
+        }
+        Ok(cubes)
+    }
+    fn part1(cubes: &Self::Input) -> anyhow::Result<Answer> {
+        let mut nb_faces = cubes.len() * 6;
+        let mut iter = cubes.iter();
+        while let Some(&c1) = iter.next() {
+            for &c2 in iter.clone() {
+                if c1
+                    .into_iter()
+                    .zip(c2)
+                    .map(|(c1, c2)| (c1 - c2).abs())
+                    .sum::<i8>()
+                    == 1
+                {
+                    nb_faces -= 2;
+                }
+            }
+        }
+        Ok(nb_faces.into())
+    }
+    fn part2(cubes: &Self::Input) -> anyhow::Result<Answer> {
+        let (min, max) = bounds(cubes);
+        let (_, nb_faces) = flood(cubes, min, max);
+        Ok(nb_faces.into())
+    }
+}
@@ -0,0 +1,261 @@
+use crate::parallel;
+use crate::pareto::{self, Problem};
+use crate::parse::{self, Line, ParseError};
+use crate::solution::{Answer, Solution};
+use regex::Regex;
+
+lazy_static::lazy_static! {
+    static ref RE: Regex =
+        Regex::new(
+            "^Blueprint (\\d+): \
+             Each ore robot costs (\\d+) ore. \
+             Each clay robot costs (\\d+) ore. \
+             Each obsidian robot costs (\\d+) ore and (\\d+) clay. \
+             Each geode robot costs (\\d+) ore and (\\d+) obsidian.$"
+        ).unwrap();
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct State {
+    remaining: u32,
+    nb_geode: u32,
+    nb_ore_robots: u32,
+    nb_ore: u32,
+    nb_clay_robots: u32,
+    nb_clay: u32,
+    nb_obsidian_robots: u32,
+    nb_obsidian: u32,
+}
+impl State {
+    fn step(&mut self) {
+        self.remaining -= 1;
+        self.nb_ore += self.nb_ore_robots;
+        self.nb_clay += self.nb_clay_robots;
+        self.nb_obsidian += self.nb_obsidian_robots;
+    }
+}
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: u32,
+    nb_ore_for_ore: u32,
+    nb_ore_for_clay: u32,
+    nb_ore_clay_for_obsidian: (u32, u32),
+    nb_ore_obsidian_for_geode: (u32, u32),
+}
+impl<'a> TryFrom<Line<'a>> for Blueprint {
+    type Error = ParseError;
+    fn try_from(l: Line<'a>) -> Result<Self, ParseError> {
+        let c = l.captures(
+            &RE,
+            "Blueprint N: Each ore robot costs N ore. Each clay robot costs N ore. \
+             Each obsidian robot costs N ore and N clay. \
+             Each geode robot costs N ore and N obsidian.",
+        )?;
+        let nb = |i| l.parse(&c[i], "a number");
+        Ok(Self {
+            id: nb(1)?,
+            nb_ore_for_ore: nb(2)?,
+            nb_ore_for_clay: nb(3)?,
+            nb_ore_clay_for_obsidian: (nb(4)?, nb(5)?),
+            nb_ore_obsidian_for_geode: (nb(6)?, nb(7)?),
+        })
+    }
+}
+impl Problem for Blueprint {
+    type State = State;
+    /// All the states are compared, whatever the remaining time, as
+    /// the states with the more remaining time are expanded first.
+    type Key = ();
+    type Value = u32;
+
+    fn key(&self, _: &State) {}
+    fn successors(&self, &state: &State) -> impl Iterator<Item = State> {
+        self.make_geode_robot(state)
+            .into_iter()
+            .chain(self.make_obsidian_robot(state))
+            .chain(self.make_clay_robot(state))
+            .chain(self.make_ore_robot(state))
+    }
+    fn objective(&self, state: &State) -> u32 {
+        state.nb_geode
+    }
+    fn dominates(&self, a: &State, b: &State) -> bool {
+        if b.remaining <= 2 {
+            return b.nb_geode <= a.nb_geode;
+        }
+        b.nb_geode <= a.nb_geode
+            && b.nb_ore <= a.nb_ore
+            && b.nb_ore_robots <= a.nb_ore_robots
+            && b.nb_clay <= a.nb_clay
+            && b.nb_clay_robots <= a.nb_clay_robots
+            && b.nb_obsidian <= a.nb_obsidian
+            && b.nb_obsidian_robots <= a.nb_obsidian_robots
+    }
+    /// A new geode robot every minute.
+    fn upper_bound(&self, state: &State) -> Option<u32> {
+        let r = state.remaining;
+        Some(state.nb_geode + r * r.saturating_sub(1) / 2)
+    }
+}
+impl Blueprint {
+    fn quality_level(&self) -> u32 {
+        self.id * self.nb_geodes(24)
+    }
+    /// The most geodes that can be opened in `remaining` minutes.
+    pub fn nb_geodes(&self, remaining: u32) -> u32 {
+        let state = State {
+            remaining,
+            nb_ore_robots: 1,
+            ..Default::default()
+        };
+        pareto::search(self, state).best.nb_geode
+    }
+    fn make_ore_robot(&self, mut state: State) -> Option<State> {
+        if state.nb_ore_robots
+            >= self
+                .nb_ore_for_ore
+                .max(self.nb_ore_for_clay)
+                .max(self.nb_ore_clay_for_obsidian.0)
+                .max(self.nb_ore_obsidian_for_geode.0)
+        {
+            return None;
+        }
+        loop {
+            if state.remaining <= 2 {
+                return None;
+            }
+            if state.nb_ore >= self.nb_ore_for_ore {
+                state.nb_ore -= self.nb_ore_for_ore;
+                state.step();
+                state.nb_ore_robots += 1;
+                return Some(state);
+            }
+            state.step();
+        }
+    }
+    fn make_clay_robot(&self, mut state: State) -> Option<State> {
+        if state.nb_clay_robots >= self.nb_ore_clay_for_obsidian.1 {
+            return None;
+        }
+        loop {
+            if state.remaining <= 2 {
+                return None;
+            }
+            if state.nb_ore >= self.nb_ore_for_clay {
+                state.nb_ore -= self.nb_ore_for_clay;
+                state.step();
+                state.nb_clay_robots += 1;
+                return Some(state);
+            }
+            state.step();
+        }
+    }
+    fn make_obsidian_robot(&self, mut state: State) -> Option<State> {
+        if state.nb_obsidian_robots >= self.nb_ore_obsidian_for_geode.1 {
+            return None;
+        }
+        loop {
+            if state.remaining <= 2 {
+                return None;
+            }
+            if state.nb_ore >= self.nb_ore_clay_for_obsidian.0
+                && state.nb_clay >= self.nb_ore_clay_for_obsidian.1
+            {
+                state.nb_ore -= self.nb_ore_clay_for_obsidian.0;
+                state.nb_clay -= self.nb_ore_clay_for_obsidian.1;
+                state.step();
+                state.nb_obsidian_robots += 1;
+                return Some(state);
+            }
+            state.step();
+        }
+    }
+    fn make_geode_robot(&self, mut state: State) -> Option<State> {
+        loop {
+            if state.remaining <= 1 {
+                return None;
+            }
+            if state.nb_ore >= self.nb_ore_obsidian_for_geode.0
+                && state.nb_obsidian >= self.nb_ore_obsidian_for_geode.1
+            {
+                state.nb_ore -= self.nb_ore_obsidian_for_geode.0;
+                state.nb_obsidian -= self.nb_ore_obsidian_for_geode.1;
+                state.step();
+                state.nb_geode += state.remaining;
+                return Some(state);
+            }
+            state.step();
+        }
+    }
+}
+
+/// Naive solutions, to check the real ones.
+pub mod naive {
+    use super::Blueprint;
+    use std::collections::HashSet;
+
+    /// [`Blueprint::nb_geodes`], trying everything at every minute.
+    pub fn nb_geodes(b: &Blueprint, remaining: u32) -> u32 {
+        // the costs in ore, clay and obsidian of the robots collecting
+        // ore, clay, obsidian and geodes
+        let costs = [
+            [b.nb_ore_for_ore, 0, 0],
+            [b.nb_ore_for_clay, 0, 0],
+            [
+                b.nb_ore_clay_for_obsidian.0,
+                b.nb_ore_clay_for_obsidian.1,
+                0,
+            ],
+            [
+                b.nb_ore_obsidian_for_geode.0,
+                0,
+                b.nb_ore_obsidian_for_geode.1,
+            ],
+        ];
+        // the robots and the collected resources
+        let mut states = HashSet::from([([1, 0, 0, 0], [0; 4])]);
+        for _ in 0..remaining {
+            let mut next = HashSet::new();
+            for (robots, resources) in states {
+                let collect = |mut r: [u32; 4]| {
+                    (0..4).for_each(|i| r[i] += robots[i]);
+                    r
+                };
+                next.insert((robots, collect(resources)));
+                for (robot, cost) in costs.iter().enumerate() {
+                    if (0..3).any(|i| resources[i] < cost[i]) {
+                        continue;
+                    }
+                    let mut r = resources;
+                    (0..3).for_each(|i| r[i] -= cost[i]);
+                    let mut robots = robots;
+                    robots[robot] += 1;
+                    next.insert((robots, collect(r)));
+                }
+            }
+            states = next;
+        }
+        states.iter().map(|(_, r)| r[3]).max().unwrap_or(0)
+    }
+}
+
+pub struct Day19;
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = Vec<Blueprint>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let blueprints = parse::lines(input).map(Blueprint::try_from);
+        Ok(blueprints.collect::<Result<_, _>>()?)
+    }
+    fn part1(blueprints: &Self::Input) -> anyhow::Result<Answer> {
+        let quality_levels = parallel::map(blueprints, Blueprint::quality_level);
+        Ok(quality_levels.iter().sum::<u32>().into())
+    }
+    fn part2(blueprints: &Self::Input) -> anyhow::Result<Answer> {
+        let first = &blueprints[..blueprints.len().min(3)];
+        let nb_geodes = parallel::map(first, |b| b.nb_geodes(32));
+        Ok(nb_geodes.iter().product::<u32>().into())
+    }
+}
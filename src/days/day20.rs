@@ -0,0 +1,81 @@
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+fn mix(v: &mut [(usize, i64)]) {
+    let len = v.len();
+    for i in 0..len {
+        let (pos, &(_, value)) = v.iter().enumerate().find(|(_, &(j, _))| i == j).unwrap();
+        let modulus = len as i64 - 1;
+        let mut new_pos = (pos as i64 + value) % modulus;
+        if new_pos < 0 {
+            new_pos += modulus;
+        }
+        let new_pos = new_pos as usize;
+        if pos < new_pos {
+            v[pos..=new_pos].rotate_left(1);
+        } else {
+            v[new_pos..=pos].rotate_right(1);
+        }
+    }
+}
+
+fn grove(v: &[(usize, i64)]) -> anyhow::Result<i64> {
+    let len = v.len();
+    let p = v
+        .iter()
+        .position(|(_, v)| *v == 0)
+        .ok_or_else(|| anyhow::anyhow!("no 0 in the numbers"))?;
+    Ok(v[(p + 1000) % len].1 + v[(p + 2000) % len].1 + v[(p + 3000) % len].1)
+}
+
+crate::params! {
+    /// The decryption of the second part.
+    pub struct Params {
+        /// The number multiplying the numbers.
+        pub key: i64 = 811_589_153,
+        /// The number of times the numbers are mixed.
+        pub rounds: usize = 10,
+    }
+}
+
+pub struct Day20;
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    /// The numbers with their original position.
+    type Input = (Vec<(usize, i64)>, Params);
+    type Params = Params;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+    fn parse_with(input: &str, params: &Params) -> anyhow::Result<Self::Input> {
+        let v = parse::lines(input)
+            .enumerate()
+            .map(|(i, l)| Ok((i, l.parse(l.text, "a number")?)));
+        let v: Vec<_> = v.collect::<Result<_, parse::ParseError>>()?;
+        if v.len() < 2 {
+            return Err(parse::eof(input, "at least 2 numbers to mix").into());
+        }
+        Ok((v, params.clone()))
+    }
+    fn validate(_: &str, (v, _): &Self::Input) -> Vec<String> {
+        match v.iter().filter(|&&(_, n)| n == 0).count() {
+            1 => vec![],
+            nb => vec![format!("exactly one 0 expected, got {}", nb)],
+        }
+    }
+    fn part1((v, _): &Self::Input) -> anyhow::Result<Answer> {
+        let mut v = v.clone();
+        mix(&mut v);
+        grove(&v).map(Into::into)
+    }
+    fn part2((v, p): &Self::Input) -> anyhow::Result<Answer> {
+        let mut v = v.clone();
+        for (_, v) in &mut v {
+            *v *= p.key;
+        }
+        for _ in 0..p.rounds {
+            mix(&mut v);
+        }
+        grove(&v).map(Into::into)
+    }
+}
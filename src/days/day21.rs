@@ -0,0 +1,155 @@
+use crate::parse::{self, Line, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::trace;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub enum Monkey {
+    Val(i64),
+    Op {
+        lhs: String,
+        rhs: String,
+        op: String,
+    },
+    Humn(i64),
+}
+impl Monkey {
+    fn try_new(l: Line) -> Result<(String, Monkey), ParseError> {
+        let expected = "`name: N` or `name: lhs OP rhs`";
+        let (name, s) = match l.text.split_once(": ") {
+            Some((name, s)) if !name.is_empty() => (name, s),
+            _ => return Err(l.error_line(expected)),
+        };
+        match *s.split(' ').collect::<Vec<_>>() {
+            [val] if name == "humn" => Ok((name.into(), Monkey::Humn(l.parse(val, "a number")?))),
+            [val] => Ok((name.into(), Monkey::Val(l.parse(val, "a number")?))),
+            [lhs, op @ ("+" | "-" | "*" | "/"), rhs] => Ok((
+                name.into(),
+                Monkey::Op {
+                    lhs: lhs.into(),
+                    rhs: rhs.into(),
+                    op: op.into(),
+                },
+            )),
+            [_, op, _] => Err(l.error(op, "`+`, `-`, `*` or `/`")),
+            _ => Err(l.error(s, expected)),
+        }
+    }
+    fn val(&self, take_humn: bool) -> Option<i64> {
+        match *self {
+            Monkey::Val(v) => Some(v),
+            Monkey::Humn(v) if take_humn => Some(v),
+            _ => None,
+        }
+    }
+}
+
+fn propagate(monkeys: &mut HashMap<String, Monkey>, take_humn: bool) -> anyhow::Result<()> {
+    let keys = monkeys.keys().cloned().collect::<Vec<_>>();
+    let mut update = true;
+    while update {
+        update = false;
+        for key in &keys {
+            let val = match &monkeys[key] {
+                Monkey::Val(_) | Monkey::Humn(_) => continue,
+                Monkey::Op { lhs, rhs, op } => {
+                    let Some(lhs) = monkeys.get(lhs).and_then(|m| m.val(take_humn)) else {
+                        continue;
+                    };
+                    let Some(rhs) = monkeys.get(rhs).and_then(|m| m.val(take_humn)) else {
+                        continue;
+                    };
+                    match op.as_str() {
+                        "*" => lhs * rhs,
+                        "+" => lhs + rhs,
+                        "/" => lhs / rhs,
+                        "-" => lhs - rhs,
+                        _ => anyhow::bail!("bad op {:?}", op),
+                    }
+                }
+            };
+            monkeys.insert(key.clone(), Monkey::Val(val));
+            update = true;
+        }
+    }
+    Ok(())
+}
+
+/// The value of `humn` for the monkey `name` to yell `equal`.
+fn inverse(monkeys: &HashMap<String, Monkey>, name: &str, equal: i64) -> anyhow::Result<i64> {
+    use Monkey::*;
+    let (lhs, op, rhs) = match &monkeys[name] {
+        Humn(_) => return Ok(equal),
+        Op { lhs, rhs, op } => (lhs, op.as_str(), rhs),
+        cur => anyhow::bail!("Unsupported node {:?}", cur),
+    };
+    let (next, next_equal) = match (&monkeys[lhs], op, &monkeys[rhs]) {
+        (&Val(m), "+", _) => (rhs, equal - m),
+        (_, "+", &Val(m)) => (lhs, equal - m),
+        (&Val(m), "*", _) => (rhs, equal / m),
+        (_, "*", &Val(m)) => (lhs, equal / m),
+        (_, "-", &Val(m)) => (lhs, equal + m),
+        (&Val(m), "-", _) => (rhs, m - equal),
+        (_, "/", &Val(m)) => (lhs, equal * m),
+        (&Val(m), "/", _) => (rhs, m / equal),
+        v => anyhow::bail!("Unsupported inversion {:?}", v),
+    };
+    trace!(
+        21,
+        "inverse",
+        monkey = name,
+        op = op,
+        equal = equal,
+        next = next,
+        next_equal = next_equal
+    );
+    inverse(monkeys, next, next_equal)
+}
+
+pub struct Day21;
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = HashMap<String, Monkey>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut monkeys = HashMap::new();
+        let mut ops = vec![];
+        for l in parse::lines(input) {
+            let (name, monkey) = Monkey::try_new(l)?;
+            if let Monkey::Op { .. } = monkey {
+                ops.push(l);
+            }
+            monkeys.insert(name, monkey);
+        }
+        // the operands, as `lhs` and `rhs` in `name: lhs OP rhs`
+        for l in ops {
+            let (_, s) = l.text.split_once(": ").unwrap();
+            let words: Vec<_> = s.split(' ').collect();
+            if let Some(&name) = [words[0], words[2]]
+                .iter()
+                .find(|&&name| !monkeys.contains_key(name))
+            {
+                return Err(l.error(name, "the name of a defined monkey").into());
+            }
+        }
+        Ok(monkeys)
+    }
+    fn part1(monkeys: &Self::Input) -> anyhow::Result<Answer> {
+        let mut monkeys = monkeys.clone();
+        propagate(&mut monkeys, true)?;
+        let root = monkeys
+            .get("root")
+            .and_then(|m| m.val(true))
+            .ok_or_else(|| anyhow::anyhow!("can't compute root"))?;
+        Ok(root.into())
+    }
+    fn part2(monkeys: &Self::Input) -> anyhow::Result<Answer> {
+        let mut monkeys = monkeys.clone();
+        match monkeys.get_mut("root") {
+            Some(Monkey::Op { op, .. }) => *op = "-".into(),
+            root => anyhow::bail!("unsupported root {:?}", root),
+        }
+        propagate(&mut monkeys, false)?;
+        Ok(inverse(&monkeys, "root", 0)?.into())
+    }
+}
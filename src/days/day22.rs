@@ -0,0 +1,197 @@
+use crate::grid::{Coord, Grid};
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+type Step = fn(&Grid<u8>, &mut Coord, &mut u8);
+
+fn step_plane(map: &Grid<u8>, coord: &mut Coord, dir: &mut u8) {
+    loop {
+        let len0 = map.height();
+        let len1 = map.width();
+        *coord = match dir {
+            0 => (coord.0, (coord.1 + 1) % len1),
+            1 => ((coord.0 + 1) % len0, coord.1),
+            2 => (coord.0, (coord.1 + len1 - 1) % len1),
+            3 => ((coord.0 + len0 - 1) % len0, coord.1),
+            _ => panic!("bad dir {}", dir),
+        };
+        if map[*coord] != b' ' {
+            break;
+        }
+    }
+}
+
+fn step_cube(map: &Grid<u8>, coord: &mut Coord, dir: &mut u8) {
+    match (*dir, *coord) {
+        (3, (0, 50..=99)) => {
+            *dir = 0;
+            *coord = (coord.1 + 100, 0)
+        }
+        (2, (100..=149, 0)) => {
+            *dir = 0;
+            *coord = (149 - coord.0, 50)
+        }
+        (2, (150..=199, 0)) => {
+            *dir = 1;
+            *coord = (0, coord.0 - 100)
+        }
+        (1, (199, 0..=49)) => {
+            *dir = 1;
+            *coord = (0, coord.1 + 100);
+        }
+        (0, (50..=99, 99)) => {
+            *dir = 3;
+            *coord = (49, coord.0 + 50);
+        }
+        (1, (49, 100..=149)) => {
+            *dir = 2;
+            *coord = (coord.1 - 50, 99);
+        }
+        (0, (100..=149, 99)) => {
+            *dir = 2;
+            *coord = (149 - coord.0, 149);
+        }
+        (0, (0..=49, 149)) => {
+            *dir = 2;
+            *coord = (149 - coord.0, 99);
+        }
+        (2, (50..=99, 50)) => {
+            *dir = 1;
+            *coord = (100, coord.0 - 50);
+        }
+        (2, (0..=49, 50)) => {
+            *dir = 0;
+            *coord = (149 - coord.0, 0);
+        }
+        (1, (149, 50..=99)) => {
+            *dir = 2;
+            *coord = (coord.1 + 100, 49);
+        }
+        (3, (0, 100..=149)) => {
+            *coord = (199, coord.1 - 100);
+        }
+        (0, (150..=199, 49)) => {
+            *dir = 3;
+            *coord = (149, coord.0 - 100);
+        }
+        (3, (100, 0..=49)) => {
+            *dir = 0;
+            *coord = (coord.1 + 50, 50);
+        }
+        _ => {
+            *coord = match *dir {
+                0 => (coord.0, coord.1 + 1),
+                1 => (coord.0 + 1, coord.1),
+                2 => (coord.0, coord.1 - 1),
+                3 => (coord.0 - 1, coord.1),
+                _ => panic!("bad dir {}", dir),
+            };
+        }
+    }
+    let point = map.get(*coord).copied();
+    if ![Some(b'.'), Some(b'#')].contains(&point) {
+        panic!("unhandled {} {:?}", dir, coord);
+    }
+}
+fn advance(map: &Grid<u8>, step: Step, mut coord: Coord, mut dir: u8) -> Option<(u8, Coord)> {
+    step(map, &mut coord, &mut dir);
+    (map[coord] == b'.').then_some((dir, coord))
+}
+
+fn walk(map: &Grid<u8>, mut instrs: &str, step: Step) -> anyhow::Result<usize> {
+    let Some(start) = map.row(0).iter().position(|&c| c == b'.') else {
+        anyhow::bail!("no open tile on the first row");
+    };
+    let mut coord = (0, start);
+    let mut dir = 0_u8;
+    loop {
+        let pos = instrs.find(['R', 'L']).unwrap_or(instrs.len());
+        let (nb, q) = instrs.split_at(pos);
+        instrs = q;
+        let nb = nb.parse::<u32>()?;
+        for _ in 0..nb {
+            let Some(new) = advance(map, step, coord, dir) else {
+                break;
+            };
+            dir = new.0;
+            coord = new.1;
+        }
+        if instrs.is_empty() {
+            break;
+        }
+        let (rotate, q) = instrs.split_at(1);
+        instrs = q;
+        match rotate {
+            "R" => dir = (dir + 1) % 4,
+            "L" => dir = (dir + 3) % 4,
+            _ => anyhow::bail!("bad rotation {}", rotate),
+        }
+    }
+    Ok((coord.0 + 1) * 1000 + (coord.1 + 1) * 4 + dir as usize)
+}
+
+pub struct Day22;
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    /// The map and the path instructions.
+    type Input = (Grid<u8>, String);
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let mut lines = parse::lines(input).collect::<Vec<_>>();
+        let Some(instrs) = lines.pop() else {
+            return Err(parse::eof(input, "the path to follow").into());
+        };
+        if let Some(i) = instrs
+            .text
+            .find(|c: char| !"LR".contains(c) && !c.is_ascii_digit())
+        {
+            return Err(instrs.error_at(i..i + 1, "a number, `L` or `R`").into());
+        }
+        match lines.pop() {
+            Some(l) if l.text.is_empty() => (),
+            Some(l) => return Err(l.error_line("an empty line").into()),
+            None => return Err(instrs.error_line("the map").into()),
+        }
+        let map = Grid::parse_padded(lines, b' ', "` `, `.` or `#`", |c| {
+            " .#".contains(c).then_some(c as u8)
+        })?;
+        Ok((map, instrs.text.into()))
+    }
+    fn validate(_: &str, (map, _): &Self::Input) -> Vec<String> {
+        let nb_tiles = map.cells().iter().filter(|&&c| c != b' ').count();
+        let side = (1..=nb_tiles).find(|s| 6 * s * s >= nb_tiles).unwrap_or(0);
+        if side == 0 || 6 * side * side != nb_tiles {
+            return vec![format!(
+                "6 equal square faces expected, got {} tiles",
+                nb_tiles
+            )];
+        }
+        // each square of the side of a face is either a face or empty
+        let mut res = vec![];
+        for i in (0..map.height()).step_by(side) {
+            for j in (0..map.width()).step_by(side) {
+                let square = (i..i + side).flat_map(|i| (j..j + side).map(move |j| (i, j)));
+                let tiles = square.filter(|&c| map.get(c).is_some_and(|&c| c != b' '));
+                match tiles.count() {
+                    0 => (),
+                    n if n == side * side => (),
+                    n => res.push(format!(
+                        "a face of {}x{} tiles expected at line {}, column {}, got {} tiles",
+                        side,
+                        side,
+                        i + 1,
+                        j + 1,
+                        n
+                    )),
+                }
+            }
+        }
+        res
+    }
+    fn part1((map, instrs): &Self::Input) -> anyhow::Result<Answer> {
+        Ok(walk(map, instrs, step_plane)?.into())
+    }
+    fn part2((map, instrs): &Self::Input) -> anyhow::Result<Answer> {
+        Ok(walk(map, instrs, step_cube)?.into())
+    }
+}
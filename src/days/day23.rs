@@ -0,0 +1,139 @@
+use crate::animate::{Frame, Show};
+use crate::grid::{Coord, Grid, Tile};
+use crate::image::Image;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+/// The elf at `coord` must not be on the border of the map.
+fn next(map: &Grid<bool>, coord: Coord, iteration: usize) -> Option<Coord> {
+    let nothing_around = map.neighbours8(coord).all(|c| !map[c]);
+    if nothing_around {
+        return None;
+    }
+    [
+        [
+            (coord.0 - 1, coord.1 - 1),
+            (coord.0 - 1, coord.1),
+            (coord.0 - 1, coord.1 + 1),
+        ],
+        [
+            (coord.0 + 1, coord.1 - 1),
+            (coord.0 + 1, coord.1),
+            (coord.0 + 1, coord.1 + 1),
+        ],
+        [
+            (coord.0 - 1, coord.1 - 1),
+            (coord.0, coord.1 - 1),
+            (coord.0 + 1, coord.1 - 1),
+        ],
+        [
+            (coord.0 - 1, coord.1 + 1),
+            (coord.0, coord.1 + 1),
+            (coord.0 + 1, coord.1 + 1),
+        ],
+    ]
+    .into_iter()
+    .cycle()
+    .skip(iteration % 4)
+    .take(4)
+    .filter_map(|l @ [_, d, _]| l.into_iter().all(|c| !map[c]).then_some(d))
+    .next()
+}
+
+/// Grow the map so that no elf is on its border.
+fn extend_map(map: &mut Grid<bool>) {
+    if map.height() == 0 {
+        return;
+    }
+    let (h, w) = (map.height(), map.width());
+    let top = map.row(0).contains(&true);
+    let bottom = map.row(h - 1).contains(&true);
+    let left = map.column(0).any(|c| *c);
+    let right = map.column(w - 1).any(|c| *c);
+    map.grow(top.into(), bottom.into(), left.into(), right.into(), false);
+}
+
+/// Play the round `k`, returning `false` if no elf moved.
+fn round(map: &mut Grid<bool>, k: usize) -> bool {
+    extend_map(map);
+
+    let mut propositions = HashMap::<_, Vec<Coord>>::new();
+    for (c, _) in map.iter().filter(|(_, &e)| e) {
+        if let Some(d) = next(map, c, k) {
+            propositions.entry(d).or_default().push(c);
+        }
+    }
+    let mut moved = false;
+    for (d, es) in propositions {
+        if es.len() == 1 {
+            map[d] = true;
+            map[es[0]] = false;
+            moved = true;
+        }
+    }
+    moved
+}
+
+/// Show the elves after each round, the 10 first ones or until they
+/// stop moving.
+pub fn animate(map: &Grid<bool>, part: u8, show: &mut Show) -> ControlFlow<()> {
+    let mut map = map.clone();
+    show(Frame::new("initial state", &map.map(Tile::to_char)))?;
+    let mut k = 0;
+    while (part != 1 || k < 10) && round(&mut map, k) {
+        k += 1;
+        show(Frame::new(format!("round {}", k), &map.map(Tile::to_char)))?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// The elves after the 10 rounds of the first part, or once they
+/// stopped moving, in black.
+pub fn image(map: &Grid<bool>, part: u8) -> Image {
+    let mut map = map.clone();
+    if part == 1 {
+        for k in 0..10 {
+            round(&mut map, k);
+        }
+    } else {
+        let mut k = 0;
+        while round(&mut map, k) {
+            k += 1;
+        }
+    }
+    map.trim(|&c| !c);
+    Image::Bitmap(map)
+}
+
+pub struct Day23;
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Grid<bool>;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let map = Grid::parse(parse::lines(input), "`.` or `#`", |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        })?;
+        Ok(map)
+    }
+    fn part1(map: &Self::Input) -> anyhow::Result<Answer> {
+        let mut map = map.clone();
+        for k in 0..10 {
+            round(&mut map, k);
+        }
+        map.trim(|&c| !c);
+        Ok(map.cells().iter().filter(|&&c| !c).count().into())
+    }
+    fn part2(map: &Self::Input) -> anyhow::Result<Answer> {
+        let mut map = map.clone();
+        let mut k = 0;
+        while round(&mut map, k) {
+            k += 1;
+        }
+        Ok((k + 1).into())
+    }
+}
@@ -0,0 +1,164 @@
+use crate::animate::{Frame, Show};
+use crate::cycle;
+use crate::grid::{Coord, Grid};
+use crate::parse;
+use crate::search;
+use crate::solution::{Answer, Solution};
+use anyhow::Context;
+use std::ops::ControlFlow;
+
+pub struct Valley {
+    /// The map at time 0, with the walls and the blizzards.
+    map: Grid<u8>,
+    start: Coord,
+    goal: Coord,
+}
+impl Valley {
+    /// Is `c` free of walls and blizzards at time `t`?
+    fn is_free(&self, c: Coord, t: usize) -> bool {
+        let h = self.map.height() - 2;
+        if self.map[c] == b'#' {
+            return false;
+        }
+        if c.0 == 0 || c.0 == h + 1 {
+            return true; // the start or the goal, no blizzard there
+        }
+        self.blizzards(c, t).next().is_none()
+    }
+    /// The blizzards at `c`, inside the valley, at time `t`.
+    fn blizzards(&self, c: Coord, t: usize) -> impl Iterator<Item = u8> + '_ {
+        let (h, w) = (self.map.height() - 2, self.map.width() - 2);
+        // from where they were at time 0
+        let (i, j) = (c.0 - 1, c.1 - 1);
+        let (t_h, t_w) = (t % h, t % w);
+        [
+            (b'>', (c.0, (j + w - t_w) % w + 1)),
+            (b'<', (c.0, (j + t_w) % w + 1)),
+            (b'v', ((i + h - t_h) % h + 1, c.1)),
+            (b'^', ((i + t_h) % h + 1, c.1)),
+        ]
+        .into_iter()
+        .filter(move |&(b, from)| self.map[from] == b)
+        .map(|(b, _)| b)
+    }
+    /// The valley at time `t`, the number of blizzards being shown
+    /// where they cross.
+    fn grid(&self, t: usize) -> Grid<char> {
+        let h = self.map.height() - 2;
+        Grid::from_fn(self.map.width(), self.map.height(), |c| {
+            if self.map[c] == b'#' || c.0 == 0 || c.0 == h + 1 {
+                return self.map[c] as char;
+            }
+            let blizzards: Vec<_> = self.blizzards(c, t).collect();
+            match *blizzards {
+                [] => '.',
+                [b] => b as char,
+                _ => char::from_digit(blizzards.len() as u32, 10).unwrap_or('*'),
+            }
+        })
+    }
+    /// The blizzards are back to their initial positions after this
+    /// time, the vertical ones cycling with the height of the valley and
+    /// the horizontal ones with its width.
+    fn period(&self) -> usize {
+        cycle::lcm(self.map.height() - 2, self.map.width() - 2).max(1)
+    }
+    /// The time to go from `origin` to `destination`, starting at time `t`.
+    fn search(&self, origin: Coord, destination: Coord, t: usize) -> anyhow::Result<usize> {
+        Ok(self.route(origin, destination, t)?.len() - 1)
+    }
+    /// The positions of the expedition on a fastest way from `origin`
+    /// to `destination`, starting at time `t`.
+    fn route(&self, origin: Coord, destination: Coord, t: usize) -> anyhow::Result<Vec<Coord>> {
+        // the time is kept modulo the period, so the search ends
+        // even if there is no way
+        let period = self.period();
+        let next = |&(t, c): &(usize, Coord)| {
+            std::iter::once(c)
+                .chain(self.map.neighbours4(c))
+                .filter(move |&n| self.is_free(n, t + 1))
+                .map(move |n| ((t + 1) % period, n))
+        };
+        let paths = search::bfs([(t % period, origin)], next, |&(_, c)| c == destination);
+        let goal = paths.goal.as_ref().context("no way through the valley")?;
+        let route = paths.path(goal).expect("the goal is reached");
+        Ok(route.into_iter().map(|(_, c)| c).collect())
+    }
+}
+
+/// Show the expedition and the blizzards each minute, on a fastest way
+/// through the valley, and back and again for the second part.
+pub fn animate(v: &Valley, part: u8, show: &mut Show) -> ControlFlow<()> {
+    let trips = match part {
+        1 => &[(v.start, v.goal)][..],
+        _ => &[(v.start, v.goal), (v.goal, v.start), (v.start, v.goal)],
+    };
+    let mut grid = v.grid(0);
+    grid[v.start] = 'E';
+    show(Frame::new("minute 0", &grid))?;
+    let mut t = 0;
+    for &(origin, destination) in trips {
+        let Ok(route) = v.route(origin, destination, t) else {
+            break;
+        };
+        for (i, &c) in route.iter().enumerate().skip(1) {
+            let mut grid = v.grid(t + i);
+            grid[c] = 'E';
+            show(Frame::new(format!("minute {}", t + i), &grid))?;
+        }
+        t += route.len() - 1;
+    }
+    ControlFlow::Continue(())
+}
+
+pub struct Day24;
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Valley;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let lines = parse::lines(input).collect::<Vec<_>>();
+        let expected = "`#`, `.`, `>`, `<`, `v` or `^`";
+        let map = Grid::parse(lines.iter().copied(), expected, |c| {
+            "#.><v^".contains(c).then_some(c as u8)
+        })?;
+        if map.height() < 3 || map.width() < 3 {
+            return Err(parse::eof(input, "the valley").into());
+        }
+        let find = |i: usize| -> Result<Coord, parse::ParseError> {
+            let j = map.row(i).iter().position(|&c| c == b'.');
+            Ok((
+                i,
+                j.ok_or_else(|| lines[i].error_line("a wall with an opening"))?,
+            ))
+        };
+        let start = find(0)?;
+        let goal = find(map.height() - 1)?;
+        Ok(Valley { map, start, goal })
+    }
+    fn validate(_: &str, v: &Self::Input) -> Vec<String> {
+        let (h, w) = (v.map.height(), v.map.width());
+        let border = (0..w)
+            .flat_map(|j| [(0, j), (h - 1, j)])
+            .chain((0..h).flat_map(|i| [(i, 0), (i, w - 1)]));
+        let mut openings = border.filter(|&c| v.map[c] != b'#').collect::<Vec<_>>();
+        openings.sort();
+        openings.dedup();
+        match openings == [v.start, v.goal] {
+            true => vec![],
+            false => vec![format!(
+                "walls expected around the valley, except at its entrance and exit, got {} openings",
+                openings.len()
+            )],
+        }
+    }
+    fn part1(v: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(v.search(v.start, v.goal, 0)?.into())
+    }
+    fn part2(v: &Self::Input) -> anyhow::Result<Answer> {
+        let first = v.search(v.start, v.goal, 0)?;
+        let get_back = v.search(v.goal, v.start, first)?;
+        let and_then = v.search(v.start, v.goal, first + get_back)?;
+        Ok((first + get_back + and_then).into())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::parse::{self, Line, ParseError};
+use crate::solution::{Answer, Solution, Streaming};
+use std::io::BufRead;
+
+fn decode(l: Line) -> Result<u64, ParseError> {
+    if !matches!(l.text.chars().next(), Some('1' | '2')) {
+        return Err(l.error_at(0..1, "`1` or `2`"));
+    }
+    let mut res = 0u64;
+    for (i, c) in l.text.char_indices() {
+        let digit = match c {
+            '2' => 2,
+            '1' => 1,
+            '0' => 0,
+            '-' => -1,
+            '=' => -2,
+            _ => return Err(l.error_at(i..i + 1, "`2`, `1`, `0`, `-` or `=`")),
+        };
+        res = res
+            .checked_mul(5)
+            .and_then(|r| r.checked_add_signed(digit))
+            .ok_or_else(|| l.error_line("a number fitting in 64 bits"))?;
+    }
+    Ok(res)
+}
+
+pub(crate) fn encode(i: u64) -> String {
+    if i == 0 {
+        return "0".into();
+    }
+    // the carries of the negative digits may not fit in 64 bits
+    let mut i = u128::from(i);
+    let mut s = String::new();
+    while i != 0 {
+        match i % 5 {
+            0 => s.push('0'),
+            1 => s.push('1'),
+            2 => s.push('2'),
+            3 => {
+                s.push('=');
+                i += 5;
+            }
+            4 => {
+                s.push('-');
+                i += 5;
+            }
+            _ => unreachable!(),
+        }
+        i /= 5;
+    }
+    s.chars().rev().collect()
+}
+
+pub struct Day25;
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const STREAMING: Streaming = Streaming::SinglePass;
+    /// The sum of the numbers, added while reading them.
+    type Input = u64;
+    type Params = ();
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Self::parse_reader(&mut input.as_bytes())
+    }
+    fn parse_reader(input: &mut dyn BufRead) -> anyhow::Result<Self::Input> {
+        let mut sum = 0u64;
+        parse::read_lines(input, |l| {
+            sum = sum
+                .checked_add(decode(l)?)
+                .ok_or_else(|| l.error_line("a number keeping the sum in 64 bits"))?;
+            Ok(())
+        })?;
+        Ok(sum)
+    }
+    fn part1(&sum: &Self::Input) -> anyhow::Result<Answer> {
+        Ok(encode(sum).into())
+    }
+    fn part2(_: &Self::Input) -> anyhow::Result<Answer> {
+        // There is no puzzle, just a star for finishing the calendar.
+        Ok(Answer::None)
+    }
+}
@@ -0,0 +1,827 @@
+//! Seeded random puzzle inputs, to stress the solutions on other
+//! inputs than the ones of `data/`.
+//!
+//! The same day, size and seed always give the same input, valid for
+//! the two parts. The size is the number of the main elements of the
+//! day:
+//!
+//! | day | size                      | day | size                          |
+//! |-----|---------------------------|-----|-------------------------------|
+//! | 1   | elves                     | 14  | rock paths                    |
+//! | 2   | rounds                    | 15  | sensors, plus 4 far away ones |
+//! | 3   | groups of 3 rucksacks     | 16  | valves, at most 15 with flow  |
+//! | 4   | pairs                     | 17  | jets                          |
+//! | 5   | moves                     | 18  | cubes                         |
+//! | 6   | characters                | 19  | blueprints                    |
+//! | 7   | directories               | 20  | numbers                       |
+//! | 8   | side of the forest        | 21  | about the number of monkeys   |
+//! | 9   | motions                   | 22  | instructions, on a 50 cube    |
+//! | 10  | instructions              | 23  | side of the grove             |
+//! | 11  | monkeys, at most 8        | 24  | width of the valley           |
+//! | 12  | width of the map          | 25  | numbers                       |
+//! | 13  | pairs of packets          |     |                               |
+//!
+//! Day 22 only solves the cube net of the real inputs, so the map is
+//! always this net, with faces of 50 tiles.
+
+use crate::days::day24::Day24;
+use crate::days::{day25, NB_DAYS};
+use crate::solution::Solution;
+use anyhow::bail;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// A small pseudo random generator (SplitMix64), good enough for
+/// puzzle inputs and reproducible without any dependency.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+    /// A number in `0..n`, `n` being non zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+    /// A number in `lo..=hi`.
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + (self.next_u64() % (hi - lo + 1) as u64) as i64
+    }
+    /// `true` with a probability of `1 / n`.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+    pub fn choose<'a, T>(&mut self, v: &'a [T]) -> &'a T {
+        &v[self.below(v.len())]
+    }
+    pub fn shuffle<T>(&mut self, v: &mut [T]) {
+        for i in (1..v.len()).rev() {
+            v.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// The default size of each day, close to the real inputs.
+pub const DEFAULT_SIZES: [usize; NB_DAYS as usize] = [
+    250, 2500, 100, 1000, 500, 4096, 180, 99, 2000, 160, 8, 160, 150, 150, 30, 55, 10091, 2800, 30,
+    5000, 2000, 2000, 72, 150, 120,
+];
+
+/// Generate an input for a day.
+pub fn generate(day: u8, size: usize, seed: u64) -> anyhow::Result<String> {
+    let rng = &mut Rng::new(seed);
+    let size = size.max(1);
+    let input = match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, size),
+        9 => day09(rng, size),
+        10 => day10(rng, size),
+        11 if size > 8 => bail!("at most 8 monkeys for day 11, to avoid overflows"),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        13 => day13(rng, size),
+        14 => day14(rng, size),
+        15 => day15(rng, size),
+        16 if size > 26 * 26 => bail!("at most {} valves for day 16", 26 * 26),
+        16 => day16(rng, size),
+        17 => day17(rng, size),
+        18 if size > 4000 => bail!("at most 4000 cubes for day 18"),
+        18 => day18(rng, size),
+        19 => day19(rng, size),
+        20 => day20(rng, size),
+        21 => day21(rng, size),
+        22 => day22(rng, size),
+        23 => day23(rng, size),
+        24 => day24(rng, size),
+        25 => day25(rng, size),
+        _ => bail!("day {} is not in 1..={}", day, NB_DAYS),
+    };
+    Ok(input)
+}
+
+fn day01(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for elf in 0..size {
+        if elf > 0 {
+            s.push('\n');
+        }
+        for _ in 0..rng.range(1, 15) {
+            writeln!(s, "{}", rng.range(1000, 70000)).unwrap();
+        }
+    }
+    s
+}
+
+fn day02(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size {
+        let (a, x) = (rng.choose(b"ABC"), rng.choose(b"XYZ"));
+        writeln!(s, "{} {}", *a as char, *x as char).unwrap();
+    }
+    s
+}
+
+/// Each rucksack has its own items, except the badge of the group, and
+/// only one item is in its two compartments.
+fn day03(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size {
+        let mut items: Vec<u8> = (b'a'..=b'z').chain(b'A'..=b'Z').collect();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        for own in items[1..].chunks(17) {
+            let (&shared, own) = own.split_first().unwrap();
+            let (first_items, second_items) = own.split_at(8);
+            let len = rng.range(4, 24) as usize;
+            let mut first = vec![shared, badge];
+            while first.len() < len {
+                first.push(*rng.choose(first_items));
+            }
+            let mut second = vec![shared];
+            while second.len() < len {
+                second.push(*rng.choose(second_items));
+            }
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+            first.extend(second);
+            writeln!(s, "{}", String::from_utf8(first).unwrap()).unwrap();
+        }
+    }
+    s
+}
+
+fn day04(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    let mut range = || {
+        let a = rng.range(1, 99);
+        (a, rng.range(a, 99))
+    };
+    for _ in 0..size {
+        let ((a, b), (c, d)) = (range(), range());
+        writeln!(s, "{}-{},{}-{}", a, b, c, d).unwrap();
+    }
+    s
+}
+
+/// The moves are simulated to never take more crates than there is.
+fn day05(rng: &mut Rng, size: usize) -> String {
+    let mut stacks: Vec<Vec<u8>> = (0..9)
+        .map(|_| {
+            (0..rng.range(1, 8))
+                .map(|_| b'A' + rng.below(26) as u8)
+                .collect()
+        })
+        .collect();
+    let mut s = String::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    for level in (0..height).rev() {
+        let row = stacks.iter().map(|v| match v.get(level) {
+            Some(&c) => format!("[{}]", c as char),
+            None => "   ".into(),
+        });
+        writeln!(s, "{}", row.collect::<Vec<_>>().join(" ")).unwrap();
+    }
+    let numbers = (1..=stacks.len()).map(|i| format!(" {} ", i));
+    writeln!(s, "{}\n", numbers.collect::<Vec<_>>().join(" ")).unwrap();
+
+    for _ in 0..size {
+        let from = loop {
+            let i = rng.below(stacks.len());
+            if !stacks[i].is_empty() {
+                break i;
+            }
+        };
+        let to = (from + rng.range(1, 8) as usize) % stacks.len();
+        let nb = rng.range(1, stacks[from].len() as i64) as usize;
+        let len = stacks[from].len();
+        let moved = stacks[from].split_off(len - nb);
+        stacks[to].extend(moved);
+        writeln!(s, "move {} from {} to {}", nb, from + 1, to + 1).unwrap();
+    }
+    s
+}
+
+/// Only 13 different characters, with 14 different ones somewhere
+/// after the middle.
+fn day06(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(14);
+    let mut s: Vec<u8> = (0..size).map(|_| b'a' + rng.below(13) as u8).collect();
+    let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut marker);
+    let at = rng.range((size / 2 - 7) as i64, (size - 14) as i64) as usize;
+    s[at..at + 14].copy_from_slice(&marker[..14]);
+    String::from_utf8(s).unwrap() + "\n"
+}
+
+fn lowercase(rng: &mut Rng, len: usize) -> String {
+    (0..len)
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect()
+}
+
+/// About 2 files per directory, for a total size of at most 60000000,
+/// and a big file at the root to use more than 40000000, so some space
+/// has to be freed.
+fn day07(rng: &mut Rng, size: usize) -> String {
+    struct Dir {
+        children: Vec<usize>,
+        files: Vec<i64>,
+    }
+    fn visit(s: &mut String, rng: &mut Rng, dirs: &[Dir], dir: usize) {
+        writeln!(s, "$ ls").unwrap();
+        let mut names = HashSet::new();
+        let mut unique_name = |rng: &mut Rng| loop {
+            let len = rng.range(1, 8) as usize;
+            let n = lowercase(rng, len);
+            if names.insert(n.clone()) {
+                break n;
+            }
+        };
+        let children: Vec<_> = dirs[dir]
+            .children
+            .iter()
+            .map(|&c| (c, unique_name(rng)))
+            .collect();
+        for (_, n) in &children {
+            writeln!(s, "dir {}", n).unwrap();
+        }
+        for size in &dirs[dir].files {
+            let n = unique_name(rng);
+            let ext = ["", ".txt", ".dat", ".log"][rng.below(4)];
+            writeln!(s, "{} {}{}", size, n, ext).unwrap();
+        }
+        for (c, n) in &children {
+            writeln!(s, "$ cd {}", n).unwrap();
+            visit(s, rng, dirs, *c);
+            writeln!(s, "$ cd ..").unwrap();
+        }
+    }
+    let max_file = (15_000_000 / size as i64).max(2000);
+    let mut dirs: Vec<_> = (0..size)
+        .map(|_| Dir {
+            children: vec![],
+            files: (0..rng.range(0, 4))
+                .map(|_| rng.range(1000, max_file))
+                .collect(),
+        })
+        .collect();
+    for d in 1..size {
+        dirs[rng.below(d)].children.push(d);
+    }
+    let total: i64 = dirs.iter().flat_map(|d| &d.files).sum();
+    if total < 41_000_000 {
+        dirs[0]
+            .files
+            .push(41_000_000 - total + rng.range(0, 4_000_000));
+    }
+    let mut s = "$ cd /\n".to_string();
+    visit(&mut s, rng, &dirs, 0);
+    s
+}
+
+fn day08(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size {
+        let row: String = (0..size)
+            .map(|_| (b'0' + rng.below(10) as u8) as char)
+            .collect();
+        writeln!(s, "{}", row).unwrap();
+    }
+    s
+}
+
+fn day09(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size {
+        let dir = *rng.choose(b"UDLR") as char;
+        writeln!(s, "{} {}", dir, rng.range(1, 20)).unwrap();
+    }
+    s
+}
+
+/// The sprite stays on the screen.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    let mut x = 1;
+    for _ in 0..size {
+        if rng.one_in(3) {
+            writeln!(s, "noop").unwrap();
+        } else {
+            let v = loop {
+                let v = rng.range(-20, 20);
+                if v != 0 && (0..40).contains(&(x + v)) {
+                    break v;
+                }
+            };
+            x += v;
+            writeln!(s, "addx {}", v).unwrap();
+        }
+    }
+    s
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Square,
+    Mul(u64),
+    Add(u64),
+}
+
+/// Monkeys are drawn until the 20 rounds of the first part don't
+/// overflow, without `old * old` and then with only additions after
+/// too many tries (the second part is bounded by the product of the
+/// divisors, all primes).
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    struct Monkey {
+        items: Vec<u64>,
+        op: Operation,
+        test: u64,
+        targets: [usize; 2],
+    }
+    let draw = |rng: &mut Rng, tries: usize| {
+        let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut primes);
+        let square = Some(rng.below(size)).filter(|_| tries < 100);
+        (0..size)
+            .map(|i| {
+                let others: Vec<_> = (0..size).filter(|&j| j != i).collect();
+                let if_true = *rng.choose(&others);
+                let if_false = loop {
+                    let j = *rng.choose(&others);
+                    if j != if_true || others.len() == 1 {
+                        break j;
+                    }
+                };
+                Monkey {
+                    items: (0..rng.range(1, 8))
+                        .map(|_| rng.range(50, 99) as u64)
+                        .collect(),
+                    op: match rng.below(2) {
+                        _ if square == Some(i) => Operation::Square,
+                        0 if tries < 200 => Operation::Mul(rng.range(2, 19) as u64),
+                        _ => Operation::Add(rng.range(1, 8) as u64),
+                    },
+                    test: primes[i],
+                    targets: [if_true, if_false],
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    let fits = |monkeys: &[Monkey]| {
+        let mut items: Vec<_> = monkeys.iter().map(|m| m.items.clone()).collect();
+        for _ in 0..20 {
+            for (i, m) in monkeys.iter().enumerate() {
+                for item in std::mem::take(&mut items[i]) {
+                    let new = match m.op {
+                        Operation::Square => item.checked_mul(item),
+                        Operation::Mul(n) => item.checked_mul(n),
+                        Operation::Add(n) => item.checked_add(n),
+                    };
+                    let Some(new) = new.map(|n| n / 3) else {
+                        return false;
+                    };
+                    let to = m.targets[usize::from(new % m.test != 0)];
+                    items[to].push(new);
+                }
+            }
+        }
+        true
+    };
+    let monkeys = (0..)
+        .map(|tries| draw(rng, tries))
+        .find(|monkeys| fits(monkeys))
+        .unwrap();
+
+    let mut s = String::new();
+    for (i, m) in monkeys.iter().enumerate() {
+        if i > 0 {
+            s.push('\n');
+        }
+        let items: Vec<_> = m.items.iter().map(u64::to_string).collect();
+        let op = match m.op {
+            Operation::Square => "old * old".into(),
+            Operation::Mul(n) => format!("old * {}", n),
+            Operation::Add(n) => format!("old + {}", n),
+        };
+        writeln!(s, "Monkey {}:", i).unwrap();
+        writeln!(s, "  Starting items: {}", items.join(", ")).unwrap();
+        writeln!(s, "  Operation: new = {}", op).unwrap();
+        writeln!(s, "  Test: divisible by {}", m.test).unwrap();
+        writeln!(s, "    If true: throw to monkey {}", m.targets[0]).unwrap();
+        writeln!(s, "    If false: throw to monkey {}", m.targets[1]).unwrap();
+    }
+    s
+}
+
+/// The elevation rises by at most 1 from the top left corner (`S`) to
+/// the bottom right one (`E`), with some holes out of the top row and
+/// the right column, so there is always a path.
+fn day12(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = (size / 4).max(5);
+    let mut s = String::new();
+    for r in 0..height {
+        for c in 0..width {
+            let mut e = (r + c) * 26 / (width + height - 1);
+            if r > 0 && c + 1 < width {
+                e = e.saturating_sub(rng.below(4));
+            }
+            s.push(match (r, c) {
+                (0, 0) => 'S',
+                _ if (r, c) == (height - 1, width - 1) => 'E',
+                _ => (b'a' + e as u8) as char,
+            });
+        }
+        s.push('\n');
+    }
+    s
+}
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let items: Vec<_> = (0..rng.range(0, 5))
+        .map(|_| match depth < 4 && rng.one_in(3) {
+            true => packet(rng, depth + 1),
+            false => rng.range(0, 10).to_string(),
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+fn day13(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for i in 0..size {
+        if i > 0 {
+            s.push('\n');
+        }
+        writeln!(s, "{}\n{}", packet(rng, 0), packet(rng, 0)).unwrap();
+    }
+    s
+}
+
+fn day14(rng: &mut Rng, size: usize) -> String {
+    let spread = (size as i64 / 3).max(50);
+    let mut s = String::new();
+    for _ in 0..size {
+        let (mut x, mut y) = (rng.range(500 - spread, 500 + spread), rng.range(13, 170));
+        let mut points = vec![format!("{},{}", x, y)];
+        for i in 0..rng.range(1, 5) {
+            let d = rng.range(-8, 8);
+            if i % 2 == 0 {
+                x = (x + d).clamp(490 - spread, 510 + spread);
+            } else {
+                y = (y + d).clamp(13, 175);
+            }
+            points.push(format!("{},{}", x, y));
+        }
+        writeln!(s, "{}", points.join(" -> ")).unwrap();
+    }
+    s
+}
+
+/// Every sensor's beacon is as far as possible without seeing the
+/// distress beacon. 4 sensors far away in the diagonals cover all the
+/// search space except the distress beacon, keeping it unique.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    const SPACE: i64 = 4_000_000;
+    let distress = (rng.range(0, SPACE), rng.range(0, SPACE));
+    let dist = |a: (i64, i64), b: (i64, i64)| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+    let mut sensors = vec![];
+    for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let d = rng.range(SPACE, SPACE + SPACE / 4);
+        sensors.push((distress.0 + dx * d, distress.1 + dy * d));
+    }
+    while sensors.len() < size + 4 {
+        let sensor = (rng.range(0, SPACE), rng.range(0, SPACE));
+        if dist(sensor, distress) > 1 {
+            sensors.push(sensor);
+        }
+    }
+    rng.shuffle(&mut sensors);
+
+    let mut s = String::new();
+    for sensor in sensors {
+        let r = dist(sensor, distress) - 1;
+        let dx = rng.range(-r, r);
+        let dy = (r - dx.abs()) * if rng.one_in(2) { 1 } else { -1 };
+        let beacon = (sensor.0 + dx, sensor.1 + dy);
+        writeln!(
+            s,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            sensor.0, sensor.1, beacon.0, beacon.1
+        )
+        .unwrap();
+    }
+    s
+}
+
+/// A random tree, plus some tunnels, as the real inputs have no dead
+/// end.
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut names: Vec<String> = (b'A'..=b'Z')
+        .flat_map(|a| (b'A'..=b'Z').map(move |b| String::from_utf8(vec![a, b]).unwrap()))
+        .filter(|n| n != "AA")
+        .collect();
+    rng.shuffle(&mut names);
+    names.insert(0, "AA".into());
+    names.truncate(size);
+
+    let mut tunnels = vec![vec![]; size];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for v in 1..size {
+        connect(v, rng.below(v));
+    }
+    for _ in 0..size / 3 {
+        connect(rng.below(size), rng.below(size));
+    }
+    let mut rates = vec![0; size];
+    let nb_flows = (size / 4).clamp(1, 15);
+    for v in (1..size).take(nb_flows) {
+        rates[v] = rng.range(3, 25);
+    }
+
+    let mut valves: Vec<_> = (0..size).collect();
+    rng.shuffle(&mut valves);
+    let mut s = String::new();
+    for v in valves {
+        let to: Vec<_> = tunnels[v].iter().map(|&t| names[t].as_str()).collect();
+        let tunnels = match to.len() {
+            1 => "tunnel leads to valve",
+            _ => "tunnels lead to valves",
+        };
+        let (name, rate, to) = (&names[v], rates[v], to.join(", "));
+        writeln!(
+            s,
+            "Valve {} has flow rate={}; {} {}",
+            name, rate, tunnels, to
+        )
+        .unwrap();
+    }
+    s
+}
+
+fn day17(rng: &mut Rng, size: usize) -> String {
+    let jets: String = (0..size).map(|_| *rng.choose(&['<', '>'])).collect();
+    jets + "\n"
+}
+
+/// A lava droplet, in a ball of radius 10.
+fn day18(rng: &mut Rng, size: usize) -> String {
+    let mut cubes = HashSet::new();
+    let mut s = String::new();
+    while cubes.len() < size {
+        let c = [(); 3].map(|_| rng.range(0, 20));
+        let d2: i64 = c.iter().map(|x| (x - 10) * (x - 10)).sum();
+        if d2 <= 100 && cubes.insert(c) {
+            writeln!(s, "{},{},{}", c[0], c[1], c[2]).unwrap();
+        }
+    }
+    s
+}
+
+fn day19(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for id in 1..=size {
+        writeln!(
+            s,
+            "Blueprint {}: \
+             Each ore robot costs {} ore. \
+             Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            id,
+            rng.range(2, 4),
+            rng.range(2, 4),
+            rng.range(2, 4),
+            rng.range(5, 20),
+            rng.range(2, 4),
+            rng.range(7, 20),
+        )
+        .unwrap();
+    }
+    s
+}
+
+/// Exactly one zero, and at least one other number to move around.
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut v: Vec<i64> = (1..size)
+        .map(|_| loop {
+            match rng.range(-10000, 10000) {
+                0 => continue,
+                n => break n,
+            }
+        })
+        .collect();
+    v.insert(rng.below(size), 0);
+    let mut s = String::new();
+    for n in v {
+        writeln!(s, "{}", n).unwrap();
+    }
+    s
+}
+
+enum Expr {
+    Num(i64),
+    Humn(i64),
+    Op(Box<Expr>, char, Box<Expr>),
+}
+
+/// An expression of `value` with `nb` numbers, all the intermediate
+/// values being positive and the divisions exact.
+fn expr(rng: &mut Rng, value: i64, nb: usize) -> Expr {
+    if nb <= 1 && value > 0 {
+        return Expr::Num(value);
+    }
+    let (l, r) = (nb / 2, nb - nb / 2);
+    let divisor = (2..=20).filter(|d| value % d == 0).nth(rng.below(3));
+    let (a, op, b) = match rng.below(4) {
+        0 if value >= 2 => {
+            let a = rng.range(1, value - 1);
+            (a, '+', value - a)
+        }
+        1 if value > 0 && divisor.is_some() => (value / divisor.unwrap(), '*', divisor.unwrap()),
+        2 if value > 0 && value < 1_000_000_000 => {
+            let b = rng.range(2, 5);
+            (value * b, '/', b)
+        }
+        _ => {
+            let b = rng.range(1, value.clamp(1, 1000)) - value.min(0);
+            (value + b, '-', b)
+        }
+    };
+    Expr::Op(Box::new(expr(rng, a, l)), op, Box::new(expr(rng, b, r)))
+}
+
+/// `humn` is only on one side of `root`, with only additions,
+/// subtractions and multiplications on its path, so the answer of the
+/// second part is an integer.
+fn day21(rng: &mut Rng, size: usize) -> String {
+    fn name(rng: &mut Rng, names: &mut HashSet<String>) -> String {
+        loop {
+            let n = lowercase(rng, 4);
+            if n != "root" && n != "humn" && names.insert(n.clone()) {
+                break n;
+            }
+        }
+    }
+    fn write(s: &mut String, rng: &mut Rng, names: &mut HashSet<String>, e: &Expr) -> String {
+        let n = match e {
+            Expr::Humn(_) => "humn".into(),
+            _ => name(rng, names),
+        };
+        let job = match e {
+            Expr::Num(v) | Expr::Humn(v) => v.to_string(),
+            Expr::Op(a, op, b) => {
+                let (a, b) = (write(s, rng, names, a), write(s, rng, names, b));
+                format!("{} {} {}", a, op, b)
+            }
+        };
+        writeln!(s, "{}: {}", n, job).unwrap();
+        n
+    }
+
+    let depth = (size / 30).max(1);
+    let per_step = (size / (4 * depth)).max(1) as i64;
+    let (humn, answer) = (rng.range(1, 5000), rng.range(1, 5000));
+    // the path is `a * humn + b`
+    let mut path = Expr::Humn(humn);
+    let (mut a, mut b) = (1i64, 0);
+    for _ in 0..depth {
+        let c = rng.range(1, 20);
+        let nb = rng.range(1, per_step) as usize;
+        let other = Box::new(expr(rng, c, nb));
+        let (op, swap) = match rng.below(4) {
+            0 if a.abs() < 1_000_000_000 => ('*', rng.one_in(2)),
+            0 | 1 => ('+', rng.one_in(2)),
+            2 => ('-', false),
+            _ => ('-', true),
+        };
+        (a, b) = match (op, swap) {
+            ('*', _) => (a * c, b * c),
+            ('+', _) => (a, b + c),
+            (_, false) => (a, b - c),
+            (_, true) => (-a, c - b),
+        };
+        let path_box = Box::new(path);
+        path = match swap {
+            false => Expr::Op(path_box, op, other),
+            true => Expr::Op(other, op, path_box),
+        };
+    }
+    let other = expr(rng, a * answer + b, (size / 4).max(1));
+    let (lhs, rhs) = match rng.one_in(2) {
+        true => (path, other),
+        false => (other, path),
+    };
+
+    let mut s = String::new();
+    let names = &mut HashSet::new();
+    let (lhs, rhs) = (
+        write(&mut s, rng, names, &lhs),
+        write(&mut s, rng, names, &rhs),
+    );
+    writeln!(s, "root: {} + {}", lhs, rhs).unwrap();
+    let mut lines: Vec<_> = s.lines().collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+/// The cube net of the real inputs: two faces on the first row, one on
+/// the second, two on the third and one on the last.
+fn day22(rng: &mut Rng, size: usize) -> String {
+    const FACES: [&[usize]; 4] = [&[1, 2], &[1], &[0, 1], &[0]];
+    let mut s = String::new();
+    for (i, faces) in FACES.iter().enumerate() {
+        for r in 0..50 {
+            s.push_str(&" ".repeat(50 * faces[0]));
+            for c in 0..50 * faces.len() {
+                let start = (i, r, c) == (0, 0, 0);
+                s.push(if !start && rng.one_in(10) { '#' } else { '.' });
+            }
+            s.push('\n');
+        }
+    }
+    s.push('\n');
+    for i in 0..size {
+        if i > 0 {
+            s.push(*rng.choose(&['L', 'R']));
+        }
+        write!(s, "{}", rng.range(1, 50)).unwrap();
+    }
+    s + "\n"
+}
+
+fn day23(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size {
+        let row: String = (0..size)
+            .map(|_| if rng.one_in(2) { '#' } else { '.' })
+            .collect();
+        writeln!(s, "{}", row).unwrap();
+    }
+    s
+}
+
+/// No vertical blizzard in the columns of the entrance and the exit,
+/// as in the real inputs. The valleys are drawn until the solution
+/// finds a way, there and back.
+fn day24(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(2);
+    let height = (size * 2 / 15).max(2);
+    let draw = |rng: &mut Rng| {
+        let mut s = format!("#.{}\n", "#".repeat(width));
+        for _ in 0..height {
+            s.push('#');
+            for c in 0..width {
+                let blizzards: &[char] = match c == 0 || c == width - 1 {
+                    true => &['<', '>'],
+                    false => &['<', '>', '^', 'v'],
+                };
+                s.push(match rng.one_in(2) {
+                    true => *rng.choose(blizzards),
+                    false => '.',
+                });
+            }
+            s.push_str("#\n");
+        }
+        writeln!(s, "{}.#", "#".repeat(width)).unwrap();
+        s
+    };
+    loop {
+        let s = draw(rng);
+        let valley = Day24::parse(&s).expect("a valid valley");
+        if Day24::part2(&valley).is_ok() {
+            return s;
+        }
+    }
+}
+
+fn day25(rng: &mut Rng, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size {
+        let digits = rng.range(1, 12) as u32;
+        let n = rng.range(1, 10i64.pow(digits));
+        writeln!(s, "{}", day25::encode(n as u64)).unwrap();
+    }
+    s
+}
@@ -0,0 +1,319 @@
+//! A 2D grid, for the maps of the days.
+//!
+//! A cell is addressed by a [`Coord`], `(row, column)` from the top
+//! left corner. When the grid grows, the coordinates of the cells
+//! change, but their [`Pos`], relative to an origin, stay the same.
+
+use crate::parse::{Line, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// The coordinates of a cell, as `(row, column)`.
+pub type Coord = (usize, usize);
+
+/// A position that stays the same when the grid grows, as `(row, column)`.
+pub type Pos = (i64, i64);
+
+/// A direction, as `(row, column)` deltas.
+pub type Dir = (isize, isize);
+
+/// Up, right, down and left.
+pub const DIRS4: [Dir; 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The 4 directions and the diagonals, clockwise from up.
+pub const DIRS8: [Dir; 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A cell that can be displayed as a character.
+pub trait Tile {
+    fn to_char(&self) -> char;
+}
+impl Tile for bool {
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+impl Tile for u8 {
+    fn to_char(&self) -> char {
+        *self as char
+    }
+}
+impl Tile for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// A rectangular grid of cells, stored by rows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    /// The position of the cell `(0, 0)`.
+    origin: Pos,
+}
+impl<T> Grid<T> {
+    /// A grid with all its cells set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(width, height, |_| fill.clone())
+    }
+    /// A grid with the cells computed by `f`.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Coord) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|i| (0..width).map(move |j| (i, j)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+            origin: (0, 0),
+        }
+    }
+    /// Parse a rectangular character map, converting each character
+    /// with `f`, `expected` describing the valid characters. The map
+    /// and its lines can't be empty.
+    pub fn parse<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        expected: &str,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Self::parse_lines(lines, None, expected, f)
+    }
+    /// Parse a character map with lines of different lengths, the
+    /// short lines being padded with `pad`.
+    pub fn parse_padded<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        pad: T,
+        expected: &str,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Self::parse_lines(lines, Some(pad), expected, f)
+    }
+    fn parse_lines<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        pad: Option<T>,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        let mut rows = vec![];
+        for l in lines {
+            if l.text.is_empty() {
+                return Err(l.error_line(expected));
+            }
+            let mut row = vec![];
+            for (i, c) in l.text.char_indices() {
+                row.push(f(c).ok_or_else(|| l.error_at(i..i + c.len_utf8(), expected))?);
+            }
+            rows.push((l, row));
+        }
+        if rows.is_empty() {
+            return Err(Line { no: 1, text: "" }.error_line("a map"));
+        }
+        let width = rows.iter().map(|(_, r)| r.len()).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (l, mut row) in rows {
+            if row.len() != width {
+                let Some(pad) = &pad else {
+                    return Err(l.error_line(format!("a line of {} characters", width)));
+                };
+                row.resize(width, pad.clone());
+            }
+            cells.extend(row);
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+            origin: (0, 0),
+        })
+    }
+    /// Set the position of the cell `(0, 0)`.
+    pub fn with_origin(self, origin: Pos) -> Self {
+        Self { origin, ..self }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn get(&self, c: Coord) -> Option<&T> {
+        (c.0 < self.height && c.1 < self.width).then(|| &self.cells[c.0 * self.width + c.1])
+    }
+    pub fn get_mut(&mut self, c: Coord) -> Option<&mut T> {
+        (c.0 < self.height && c.1 < self.width).then(|| &mut self.cells[c.0 * self.width + c.1])
+    }
+    /// The cells, by rows.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+    /// The coordinates of all the cells, by rows.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + Clone {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+    /// The cells with their coordinates, by rows.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(&self.cells)
+    }
+    /// The coordinates of the first cell matching `f`.
+    pub fn position(&self, f: impl FnMut(&T) -> bool) -> Option<Coord> {
+        let i = self.cells.iter().position(f)?;
+        Some((i / self.width, i % self.width))
+    }
+    /// A grid of the same size and origin, with the cells converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            origin: self.origin,
+        }
+    }
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|i| self.row(i))
+    }
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(j).step_by(self.width.max(1))
+    }
+    /// The cell next to `c` in the direction `d`, if in the grid.
+    pub fn step(&self, c: Coord, d: Dir) -> Option<Coord> {
+        let c = (c.0.checked_add_signed(d.0)?, c.1.checked_add_signed(d.1)?);
+        (c.0 < self.height && c.1 < self.width).then_some(c)
+    }
+    /// The up to 4 cells sharing a side with `c`.
+    pub fn neighbours4(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        DIRS4.into_iter().filter_map(move |d| self.step(c, d))
+    }
+    /// The up to 8 cells sharing a side or a corner with `c`.
+    pub fn neighbours8(&self, c: Coord) -> impl Iterator<Item = Coord> + '_ {
+        DIRS8.into_iter().filter_map(move |d| self.step(c, d))
+    }
+    /// The cells from `c` (included) to the border in the direction `d`.
+    pub fn ray(&self, c: Coord, d: Dir) -> impl Iterator<Item = Coord> + '_ {
+        let start = self.get(c).map(|_| c);
+        std::iter::successors(start, move |&c| self.step(c, d))
+    }
+    /// The position of a cell.
+    pub fn pos(&self, c: Coord) -> Pos {
+        (self.origin.0 + c.0 as i64, self.origin.1 + c.1 as i64)
+    }
+    /// The cell at a position, if in the grid.
+    pub fn coord(&self, p: Pos) -> Option<Coord> {
+        let c = (
+            usize::try_from(p.0 - self.origin.0).ok()?,
+            usize::try_from(p.1 - self.origin.1).ok()?,
+        );
+        self.get(c).map(|_| c)
+    }
+    /// Add rows and columns around the grid, filled with `fill`. The
+    /// positions of the existing cells are kept.
+    pub fn grow(&mut self, top: usize, bottom: usize, left: usize, right: usize, fill: T)
+    where
+        T: Clone,
+    {
+        let width = left + self.width + right;
+        let height = top + self.height + bottom;
+        let mut cells = Vec::with_capacity(width * height);
+        cells.resize(top * width, fill.clone());
+        for row in self.rows() {
+            cells.extend(std::iter::repeat_n(fill.clone(), left));
+            cells.extend_from_slice(row);
+            cells.extend(std::iter::repeat_n(fill.clone(), right));
+        }
+        cells.resize(width * height, fill);
+        self.cells = cells;
+        self.width = width;
+        self.height = height;
+        self.origin = (self.origin.0 - top as i64, self.origin.1 - left as i64);
+    }
+    /// Grow the grid, if needed, to contain the position `p`.
+    pub fn grow_to(&mut self, p: Pos, fill: T)
+    where
+        T: Clone,
+    {
+        let (top, left) = (self.origin.0 - p.0, self.origin.1 - p.1);
+        let bottom = p.0 - (self.origin.0 + self.height as i64 - 1);
+        let right = p.1 - (self.origin.1 + self.width as i64 - 1);
+        let n = |n: i64| n.max(0) as usize;
+        self.grow(n(top), n(bottom), n(left), n(right), fill);
+    }
+    /// Remove the border rows and columns where all the cells match `f`.
+    pub fn trim(&mut self, f: impl Fn(&T) -> bool)
+    where
+        T: Clone,
+    {
+        let keep = |c: Coord| !f(&self[c]);
+        let mut rows = (0..self.height).filter(|&i| (0..self.width).any(|j| keep((i, j))));
+        let mut cols = (0..self.width).filter(|&j| (0..self.height).any(|i| keep((i, j))));
+        let (Some(top), Some(left)) = (rows.next(), cols.next()) else {
+            *self = Self {
+                width: 0,
+                height: 0,
+                cells: vec![],
+                origin: self.origin,
+            };
+            return;
+        };
+        let bottom = rows.next_back().unwrap_or(top);
+        let right = cols.next_back().unwrap_or(left);
+        let trimmed = Self::from_fn(right - left + 1, bottom - top + 1, |(i, j)| {
+            self[(top + i, left + j)].clone()
+        });
+        *self = trimmed.with_origin(self.pos((top, left)));
+    }
+}
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+    fn index(&self, c: Coord) -> &T {
+        assert!(c.1 < self.width, "column {} out of the grid", c.1);
+        &self.cells[c.0 * self.width + c.1]
+    }
+}
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, c: Coord) -> &mut T {
+        assert!(c.1 < self.width, "column {} out of the grid", c.1);
+        &mut self.cells[c.0 * self.width + c.1]
+    }
+}
+impl<T: Tile> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for c in row {
+                write!(f, "{}", c.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+//! Export grids as images, for `aoc image`.
+//!
+//! The images are written as binary [Netpbm](https://netpbm.sourceforge.net/doc/)
+//! files, readable by most image tools and simple enough to be written
+//! without dependencies: PBM for the two-color images (the visible
+//! trees of day 8 and the elves of day 23), PPM for the others (the
+//! distances of day 12, the sand of day 14 and the cross-sections of
+//! day 18).
+
+use crate::days::{day08, day12, day14, day18, day23};
+use crate::grid::Grid;
+use crate::solution::Solution;
+use anyhow::bail;
+use std::io::{self, Write};
+
+/// A color, as red, green and blue.
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// An image, a pixel per cell of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Image {
+    /// Black and white, `true` being black.
+    Bitmap(Grid<bool>),
+    Pixmap(Grid<Rgb>),
+}
+impl Image {
+    pub fn width(&self) -> usize {
+        match self {
+            Image::Bitmap(g) => g.width(),
+            Image::Pixmap(g) => g.width(),
+        }
+    }
+    pub fn height(&self) -> usize {
+        match self {
+            Image::Bitmap(g) => g.height(),
+            Image::Pixmap(g) => g.height(),
+        }
+    }
+    /// The file extension of the image format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Bitmap(_) => "pbm",
+            Image::Pixmap(_) => "ppm",
+        }
+    }
+    /// Write the image, each cell being a square of `scale` pixels.
+    pub fn write(&self, out: &mut dyn Write, scale: usize) -> io::Result<()> {
+        let scale = scale.max(1);
+        let (w, h) = (self.width() * scale, self.height() * scale);
+        match self {
+            Image::Bitmap(g) => {
+                writeln!(out, "P4\n{} {}", w, h)?;
+                for row in g.rows() {
+                    // 8 pixels per byte, the rows being padded
+                    let mut bytes = vec![0u8; w.div_ceil(8)];
+                    for (j, _) in row.iter().enumerate().filter(|&(_, &b)| b) {
+                        for x in j * scale..(j + 1) * scale {
+                            bytes[x / 8] |= 0x80 >> (x % 8);
+                        }
+                    }
+                    for _ in 0..scale {
+                        out.write_all(&bytes)?;
+                    }
+                }
+            }
+            Image::Pixmap(g) => {
+                writeln!(out, "P6\n{} {}\n255", w, h)?;
+                for row in g.rows() {
+                    let bytes: Vec<u8> = row
+                        .iter()
+                        .flat_map(|rgb| std::iter::repeat_n(rgb, scale))
+                        .flatten()
+                        .copied()
+                        .collect();
+                    for _ in 0..scale {
+                        out.write_all(&bytes)?;
+                    }
+                }
+            }
+        }
+        out.flush()
+    }
+}
+
+/// A color from blue (0) to red (1), through cyan, green and yellow.
+pub fn heat(t: f64) -> Rgb {
+    const STOPS: [Rgb; 5] = [
+        [0, 0, 255],
+        [0, 255, 255],
+        [0, 255, 0],
+        [255, 255, 0],
+        [255, 0, 0],
+    ];
+    let t = t.clamp(0., 1.) * (STOPS.len() - 1) as f64;
+    let i = (t as usize).min(STOPS.len() - 2);
+    let f = t - i as f64;
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * f).round() as u8;
+    [0, 1, 2].map(|k| mix(STOPS[i][k], STOPS[i + 1][k]))
+}
+
+/// The image of a part of a day.
+pub fn export(day: u8, part: u8, input: &str) -> anyhow::Result<Image> {
+    if !(1..=2).contains(&part) {
+        bail!("no part {}", part);
+    }
+    Ok(match day {
+        8 => day08::image(&day08::Day08::parse(input)?),
+        12 => day12::image(&day12::Day12::parse(input)?, part)?,
+        14 => day14::image(&day14::Day14::parse(input)?, part),
+        18 => day18::image(&day18::Day18::parse(input)?),
+        23 => day23::image(&day23::Day23::parse(input)?, part),
+        _ => bail!("day {} has no image, only days 8, 12, 14, 18 and 23", day),
+    })
+}
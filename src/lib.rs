@@ -0,0 +1,24 @@
+//! Advent of Code 2022 solutions.
+//!
+//! Each day is solved in its own module in [`days`], implementing the
+//! [`solution::Solution`] trait. The [`cli`] module
+//! contains the shared command line front end used by the `aoc` runner
+//! and by the per day binaries.
+
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod cycle;
+pub mod days;
+pub mod generate;
+pub mod grid;
+pub mod image;
+pub mod parallel;
+pub mod params;
+pub mod pareto;
+pub mod parse;
+pub mod report;
+pub mod search;
+pub mod solution;
+pub mod trace;
@@ -0,0 +1,107 @@
+//! Run independent jobs on several threads, with std only.
+//!
+//! The number of threads is set once for all (by `--threads`), and is 1
+//! by default: nothing runs in parallel unless asked. It is a single
+//! budget: the jobs run on the threads don't start threads of their own,
+//! so the days run in parallel have their inner loops on a single
+//! thread. The results are always given in the order of the jobs,
+//! whatever the number of threads.
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    /// Whether the current thread is one of the threads of a job.
+    static IN_JOB: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Set the number of threads, 0 being the number of cores.
+pub fn set_threads(nb: usize) {
+    let nb = match nb {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        nb => nb,
+    };
+    THREADS.store(nb, Relaxed);
+}
+
+/// The number of threads to use, 1 inside a job already run in
+/// parallel.
+pub fn threads() -> usize {
+    match IN_JOB.get() {
+        true => 1,
+        false => THREADS.load(Relaxed),
+    }
+}
+
+/// Run `job` on `threads` threads, each calling it until it returns
+/// `false`, and collect what they `push`ed.
+fn run<R: Send>(threads: usize, job: impl Fn(&mut Vec<R>) -> bool + Sync) -> Vec<R> {
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                s.spawn(|| {
+                    IN_JOB.set(true);
+                    let mut done = vec![];
+                    while job(&mut done) {}
+                    done
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+            .collect()
+    })
+}
+
+/// `f` applied to every item, in order.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let threads = threads().min(items.len());
+    if threads <= 1 {
+        return items.iter().map(f).collect();
+    }
+    let next = AtomicUsize::new(0);
+    let mut done = run(threads, |done| {
+        let i = next.fetch_add(1, Relaxed);
+        let Some(item) = items.get(i) else {
+            return false;
+        };
+        done.push((i, f(item)));
+        true
+    });
+    done.sort_unstable_by_key(|&(i, _)| i);
+    done.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Call `f` on every item.
+pub fn for_each<T: Sync>(items: &[T], f: impl Fn(&T) + Sync) {
+    map(items, f);
+}
+
+/// The first `Some` returned by `f`, in the order of the items. The
+/// items after a found one are not given to `f`.
+pub fn find_map_first<T: Sync, R: Send>(
+    items: &[T],
+    f: impl Fn(&T) -> Option<R> + Sync,
+) -> Option<R> {
+    let threads = threads().min(items.len());
+    if threads <= 1 {
+        return items.iter().find_map(f);
+    }
+    let next = AtomicUsize::new(0);
+    let found = AtomicUsize::new(usize::MAX);
+    let done = run(threads, |done| {
+        let i = next.fetch_add(1, Relaxed);
+        if i >= items.len() || i > found.load(Relaxed) {
+            return false;
+        }
+        if let Some(r) = f(&items[i]) {
+            found.fetch_min(i, Relaxed);
+            done.push((i, r));
+        }
+        true
+    });
+    done.into_iter().min_by_key(|&(i, _)| i).map(|(_, r)| r)
+}
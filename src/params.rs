@@ -0,0 +1,162 @@
+//! The constants of the puzzles that can be changed, as the row of
+//! day 15, smaller in the example than in the real inputs.
+//!
+//! Each day having some declares them as a struct with the
+//! [`params!`](crate::params!) macro, giving their types and their
+//! default values, those of the real inputs. They are set by name from
+//! text, as `--param y=10` on the command line.
+
+use crate::solution::Solution;
+use anyhow::{bail, Context};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// The parameters of a day.
+pub trait Params: Default {
+    /// Set the parameter `key` from its value as text.
+    fn set(&mut self, key: &str, value: &str) -> anyhow::Result<()>;
+    /// The names of the parameters and their values.
+    fn values(&self) -> Vec<(&'static str, String)>;
+}
+
+/// The days without parameters.
+impl Params for () {
+    fn set(&mut self, key: &str, _: &str) -> anyhow::Result<()> {
+        bail!("unknown parameter {:?}, there are none", key)
+    }
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+}
+
+/// Parse the value of a parameter, use [`params!`](crate::params!)
+/// instead.
+pub fn parse_value<T: FromStr>(key: &str, value: &str) -> anyhow::Result<T>
+where
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| anyhow::anyhow!("bad value {:?} for {}: {}", value, key, e))
+}
+
+/// Declare the parameters of a day, as
+///
+/// ```ignore
+/// params! {
+///     pub struct Params {
+///         /// The row to scan.
+///         pub y: i64 = 2_000_000,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {
+            $($(#[$field_attr:meta])* pub $field:ident: $ty:ty = $default:expr,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name {
+            $($(#[$field_attr])* pub $field: $ty,)*
+        }
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default,)*
+                }
+            }
+        }
+        impl $crate::params::Params for $name {
+            fn set(&mut self, key: &str, value: &str) -> anyhow::Result<()> {
+                match key {
+                    $(stringify!($field) => {
+                        self.$field = $crate::params::parse_value(key, value)?;
+                    })*
+                    _ => anyhow::bail!(
+                        "unknown parameter {:?}, expected {}",
+                        key,
+                        [$(stringify!($field)),*].join(", ")
+                    ),
+                }
+                Ok(())
+            }
+            fn values(&self) -> Vec<(&'static str, String)> {
+                vec![$((stringify!($field), self.$field.to_string()),)*]
+            }
+        }
+    };
+}
+
+/// A parameter given as text, as `y=10`, or `15.y=10` to only set it
+/// for day 15.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    /// The day of the parameter, every day if `None`.
+    pub day: Option<u8>,
+    pub key: String,
+    pub value: String,
+}
+impl FromStr for Param {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let bad = || format!("bad parameter {:?}, expected KEY=VALUE or DAY.KEY=VALUE", s);
+        let (key, value) = s.split_once('=').with_context(bad)?;
+        let (day, key) = match key.split_once('.') {
+            Some((day, key)) => (Some(day.parse().with_context(bad)?), key),
+            None => (None, key),
+        };
+        if key.is_empty() {
+            bail!(bad());
+        }
+        Ok(Param {
+            day,
+            key: key.into(),
+            value: value.into(),
+        })
+    }
+}
+
+/// The parameters of a day, the defaults being changed by the given
+/// ones for this day.
+pub fn build<P: Params>(day: u8, params: &[Param]) -> anyhow::Result<P> {
+    let mut res = P::default();
+    for p in params.iter().filter(|p| p.day.is_none_or(|d| d == day)) {
+        res.set(&p.key, &p.value)
+            .with_context(|| format!("bad parameter of day {}", day))?;
+    }
+    Ok(res)
+}
+
+/// Parse the input of a day with the given parameters.
+pub fn parse<S: Solution>(input: &str, params: &[Param]) -> anyhow::Result<S::Input> {
+    S::parse_with(input, &build(S::DAY, params)?)
+}
+
+/// A comma separated list, as `20,60,100`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct List<T>(pub Vec<T>);
+impl<T: FromStr> FromStr for List<T> {
+    type Err = T::Err;
+    fn from_str(s: &str) -> Result<Self, T::Err> {
+        s.split(',')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.trim().parse())
+            .collect::<Result<_, _>>()
+            .map(List)
+    }
+}
+impl<T: Display> Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, t) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", t)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+//! Best first search, pruned by dominance and by an upper bound.
+//!
+//! The states are expanded from the best one, as given by their `Ord`
+//! implementation. A state dominated by an already expanded state (as
+//! having less of everything) is not expanded, neither is a state that
+//! can't do better than the best found so far. The expanded states
+//! that are not dominated form a Pareto frontier, kept by [`Frontier`].
+
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// An optimisation problem, to be solved by [`search`].
+pub trait Problem {
+    /// A state, the greatest one being expanded first.
+    type State: Clone + Ord;
+    /// The states are only compared for dominance with the states of
+    /// the same key, as the same position.
+    type Key: Eq + Hash;
+    /// The value to maximise.
+    type Value: Copy + Ord;
+
+    fn key(&self, state: &Self::State) -> Self::Key;
+    fn successors(&self, state: &Self::State) -> impl Iterator<Item = Self::State>;
+    fn objective(&self, state: &Self::State) -> Self::Value;
+    /// Is `b` useless knowing `a`? A state can only be dominated by a
+    /// state with at least the same objective.
+    fn dominates(&self, a: &Self::State, b: &Self::State) -> bool;
+    /// The best objective that `state` or its successors can reach, if known.
+    fn upper_bound(&self, _state: &Self::State) -> Option<Self::Value> {
+        None
+    }
+}
+
+/// The states that are not dominated, grouped by key.
+#[derive(Debug, Clone)]
+pub struct Frontier<K, S> {
+    /// The states of each key, by decreasing objective.
+    buckets: HashMap<K, Vec<S>>,
+}
+impl<K: Eq + Hash, S> Default for Frontier<K, S> {
+    fn default() -> Self {
+        Self {
+            buckets: HashMap::new(),
+        }
+    }
+}
+impl<K: Eq + Hash, S> Frontier<K, S> {
+    /// Insert `state` if not dominated, removing the states it
+    /// dominates. Returns `false` if `state` is dominated.
+    pub fn insert<P>(&mut self, problem: &P, key: K, state: S) -> bool
+    where
+        P: Problem<State = S>,
+    {
+        let bucket = self.buckets.entry(key).or_default();
+        let value = problem.objective(&state);
+        // Only the states with a better or equal objective can dominate.
+        let end = bucket.partition_point(|s| problem.objective(s) >= value);
+        if bucket[..end].iter().any(|s| problem.dominates(s, &state)) {
+            return false;
+        }
+        let start = bucket.partition_point(|s| problem.objective(s) > value);
+        let mut i = start;
+        while i < bucket.len() {
+            if problem.dominates(&state, &bucket[i]) {
+                bucket.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+        bucket.insert(start, state);
+        true
+    }
+    /// The number of states.
+    pub fn len(&self) -> usize {
+        self.buckets.values().map(Vec::len).sum()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The states, in no particular order.
+    pub fn into_states(self) -> impl Iterator<Item = S> {
+        self.buckets.into_values().flatten()
+    }
+}
+
+/// What happened during a search.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of states expanded.
+    pub expanded: usize,
+    /// The number of states dominated by an expanded state.
+    pub dominated: usize,
+    /// The number of states that couldn't beat the best one.
+    pub bounded: usize,
+}
+
+/// The result of a [`search`].
+#[derive(Debug, Clone)]
+pub struct Outcome<K, S> {
+    /// The expanded state with the best objective.
+    pub best: S,
+    /// The expanded states that are not dominated.
+    pub frontier: Frontier<K, S>,
+    pub stats: Stats,
+}
+
+/// Search the best state reachable from `start`.
+pub fn search<P: Problem>(problem: &P, start: P::State) -> Outcome<P::Key, P::State> {
+    let mut best = start.clone();
+    let mut frontier = Frontier::default();
+    let mut stats = Stats::default();
+    let mut q = BinaryHeap::from(vec![start]);
+    while let Some(state) = q.pop() {
+        let best_value = problem.objective(&best);
+        if problem.upper_bound(&state).is_some_and(|b| b <= best_value) {
+            stats.bounded += 1;
+            continue;
+        }
+        if !frontier.insert(problem, problem.key(&state), state.clone()) {
+            stats.dominated += 1;
+            continue;
+        }
+        stats.expanded += 1;
+        q.extend(problem.successors(&state));
+        if problem.objective(&state) > best_value {
+            best = state;
+        }
+    }
+    Outcome {
+        best,
+        frontier,
+        stats,
+    }
+}
@@ -0,0 +1,279 @@
+//! Position aware parse errors, shared by all the days.
+//!
+//! A [`ParseError`] is rendered as rustc does:
+//!
+//! ```text
+//! expected `U`, `D`, `L` or `R`
+//!  --> data/input9.txt:3:1
+//!   |
+//! 3 | X 4
+//!   | ^
+//! ```
+//!
+//! with a note giving the group of the line for the inputs made of
+//! groups of lines separated by blank lines, as the elves of day 1.
+
+use regex::{Captures, Regex};
+use std::fmt;
+use std::io::BufRead;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// An error in a puzzle input, with its position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The input file, if known.
+    pub path: Option<String>,
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The column, in characters, starting at 1.
+    pub column: usize,
+    /// The number of characters in error, at least 1.
+    pub len: usize,
+    /// The content of the line.
+    pub text: String,
+    /// What was expected, as ``a number`` or `` `noop` or `addx N` ``.
+    pub expected: String,
+    /// The group of lines, starting at 1, for the inputs parsed by
+    /// [`groups`].
+    pub group: Option<usize>,
+}
+impl ParseError {
+    /// Set the path of the input.
+    pub fn with_path(self, path: impl Into<String>) -> Self {
+        Self {
+            path: Some(path.into()),
+            ..self
+        }
+    }
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let no = self.line.to_string();
+        let pad = " ".repeat(no.len());
+        writeln!(f, "expected {}", self.expected)?;
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            pad,
+            self.path.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column
+        )?;
+        writeln!(f, "{} |", pad)?;
+        writeln!(f, "{} | {}", no, self.text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            pad,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len)
+        )?;
+        if let Some(group) = self.group {
+            write!(f, "\n{} = note: in group {}", pad, group)?;
+        }
+        Ok(())
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// Set the path of the input if the error is a [`ParseError`].
+pub fn with_path(e: anyhow::Error, path: &str) -> anyhow::Error {
+    match e.downcast::<ParseError>() {
+        Ok(e) => e.with_path(path).into(),
+        Err(e) => e,
+    }
+}
+
+/// A line of the input, with its number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// The line number, starting at 1.
+    pub no: usize,
+    pub text: &'a str,
+}
+impl<'a> Line<'a> {
+    /// An error on the bytes `range` of the line.
+    pub fn error_at(&self, range: Range<usize>, expected: impl Into<String>) -> ParseError {
+        let mut start = range.start.min(self.text.len());
+        let mut end = range.end.clamp(start, self.text.len());
+        while !self.text.is_char_boundary(start) {
+            start -= 1;
+        }
+        while !self.text.is_char_boundary(end) {
+            end += 1;
+        }
+        ParseError {
+            path: None,
+            line: self.no,
+            column: self.text[..start].chars().count() + 1,
+            len: self.text[start..end].chars().count().max(1),
+            text: self.text.into(),
+            expected: expected.into(),
+            group: None,
+        }
+    }
+    /// An error on `token`, a slice of the line. If `token` is not
+    /// part of the line, the whole line is in error.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        let range = self.text.as_bytes().as_ptr_range();
+        let token_range = token.as_bytes().as_ptr_range();
+        if range.start <= token_range.start && token_range.end <= range.end {
+            let start = token_range.start as usize - range.start as usize;
+            self.error_at(start..start + token.len(), expected)
+        } else {
+            self.error_line(expected)
+        }
+    }
+    /// An error on the whole line.
+    pub fn error_line(&self, expected: impl Into<String>) -> ParseError {
+        self.error_at(0..self.text.len(), expected)
+    }
+    /// Match the whole line with a regex, the error showing the expected grammar.
+    pub fn captures(&self, re: &Regex, grammar: &str) -> Result<Captures<'a>, ParseError> {
+        re.captures(self.text)
+            .ok_or_else(|| self.error_line(format!("`{}`", grammar)))
+    }
+    /// Parse `token`, a slice of the line.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+}
+
+/// Iterate on the lines of the input.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> + Clone {
+    input.lines().zip(1..).map(|(text, no)| Line { no, text })
+}
+
+/// Call `f` on the lines read from `input`, as given by [`lines`], but
+/// without having the whole input in memory. Returns the number of lines.
+pub fn read_lines(
+    input: &mut dyn BufRead,
+    mut f: impl FnMut(Line<'_>) -> anyhow::Result<()>,
+) -> anyhow::Result<usize> {
+    let mut buf = String::new();
+    let mut no = 0;
+    loop {
+        buf.clear();
+        if input.read_line(&mut buf)? == 0 {
+            return Ok(no);
+        }
+        no += 1;
+        let text = match buf.strip_suffix('\n') {
+            Some(text) => text.strip_suffix('\r').unwrap_or(text),
+            None => &buf,
+        };
+        f(Line { no, text })?;
+    }
+}
+
+/// A group of lines, the groups being separated by blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group<'a> {
+    /// The group number, starting at 1.
+    pub no: usize,
+    /// The lines of the group, never empty, without their trailing
+    /// whitespace.
+    pub lines: Vec<Line<'a>>,
+}
+impl Group<'_> {
+    /// Note that an error is in this group.
+    pub fn locate(&self, e: ParseError) -> ParseError {
+        ParseError {
+            group: Some(self.no),
+            ..e
+        }
+    }
+    /// An error after the last line of the group, for a missing line.
+    pub fn error_after(&self, expected: impl Into<String>) -> ParseError {
+        let no = self.lines.last().map_or(0, |l| l.no) + 1;
+        self.locate(Line { no, text: "" }.error_line(expected))
+    }
+}
+
+fn is_blank(text: &str) -> bool {
+    text.trim().is_empty()
+}
+
+/// Iterate on the groups of lines of the input, separated by blank
+/// lines. Several blank lines (or lines of whitespace) are a single
+/// separator, and the blank lines at the start or at the end are
+/// ignored.
+pub fn groups(input: &str) -> impl Iterator<Item = Group<'_>> {
+    let mut lines = lines(input).peekable();
+    let mut no = 0;
+    std::iter::from_fn(move || {
+        while lines.next_if(|l| is_blank(l.text)).is_some() {}
+        let group: Vec<_> = std::iter::from_fn(|| lines.next_if(|l| !is_blank(l.text)))
+            .map(|l| Line {
+                no: l.no,
+                text: l.text.trim_end(),
+            })
+            .collect();
+        if group.is_empty() {
+            return None;
+        }
+        no += 1;
+        Some(Group { no, lines: group })
+    })
+}
+
+/// Parse each group of lines of the input with `f`, the errors being
+/// located in their group.
+pub fn parse_groups<T>(
+    input: &str,
+    mut f: impl FnMut(&Group<'_>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    groups(input)
+        .map(|g| f(&g).map_err(|e| g.locate(e)))
+        .collect()
+}
+
+/// Call `f` on the groups of lines read from `input`, as given by
+/// [`groups`], but with only a group in memory, the parse errors being
+/// located in their group. Returns the number of groups.
+pub fn read_groups(
+    input: &mut dyn BufRead,
+    mut f: impl FnMut(&Group<'_>) -> anyhow::Result<()>,
+) -> anyhow::Result<usize> {
+    // the lines of the current group, separated by `\n`, with their numbers
+    let mut text = String::new();
+    let mut nos = vec![];
+    let mut nb = 0;
+    let mut flush = |text: &mut String, nos: &mut Vec<usize>| {
+        if nos.is_empty() {
+            return Ok(());
+        }
+        nb += 1;
+        let lines = text.split('\n').zip(nos.drain(..));
+        let group = Group {
+            no: nb,
+            lines: lines.map(|(text, no)| Line { no, text }).collect(),
+        };
+        let res = f(&group).map_err(|e| match e.downcast::<ParseError>() {
+            Ok(e) => group.locate(e).into(),
+            Err(e) => e,
+        });
+        text.clear();
+        res
+    };
+    read_lines(input, |l| {
+        if is_blank(l.text) {
+            return flush(&mut text, &mut nos);
+        }
+        if !nos.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(l.text.trim_end());
+        nos.push(l.no);
+        Ok(())
+    })?;
+    flush(&mut text, &mut nos)?;
+    Ok(nb)
+}
+
+/// An error at the end of the input.
+pub fn eof(input: &str, expected: impl Into<String>) -> ParseError {
+    let no = input.lines().count() + 1;
+    Line { no, text: "" }.error_line(expected)
+}
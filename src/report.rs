@@ -0,0 +1,202 @@
+//! Machine readable reports of the answers, for `--format json`.
+//!
+//! The days are reported as (here on the example of day 2):
+//!
+//! ```text
+//! {
+//!   "days": [
+//!     {
+//!       "day": 2,
+//!       "input_checksum": "fnv1a64:cb49de7989531fb8",
+//!       "parse_ns": 22018,
+//!       "parts": [
+//!         { "part": 1, "answer": 15, "elapsed_ns": 891 },
+//!         { "part": 2, "answer": 12, "elapsed_ns": 333 }
+//!       ]
+//!     }
+//!   ],
+//!   "elapsed_ns": 23242
+//! }
+//! ```
+//!
+//! the `days` array having a single element when a single day is run.
+//! The days solved in a single pass while reading their input (see
+//! [`Streaming::SinglePass`]) have a `solve_ns` instead of `parse_ns`,
+//! the time of their parts being only the time to give the answers.
+
+use crate::params::Param;
+use crate::solution::{Answer, Solver, Streaming};
+use crate::trace;
+use serde::Serialize;
+use std::any::Any;
+use std::io::{self, BufRead, Read};
+use std::time::{Duration, Instant};
+
+/// FNV-1a, 64 bits.
+#[derive(Debug, Clone, Copy)]
+struct Fnv(u64);
+impl Fnv {
+    fn new() -> Self {
+        Fnv(0xcbf2_9ce4_8422_2325)
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    fn checksum(self) -> String {
+        format!("fnv1a64:{:016x}", self.0)
+    }
+}
+
+/// The checksum of an input, to know on which input the answers were
+/// computed (FNV-1a, 64 bits).
+pub fn checksum(input: &str) -> String {
+    let mut fnv = Fnv::new();
+    fnv.write(input.as_bytes());
+    fnv.checksum()
+}
+
+/// A reader computing the [`checksum`] of what is read through it.
+pub struct ChecksumReader<R> {
+    inner: R,
+    fnv: Fnv,
+}
+impl<R: BufRead> ChecksumReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            fnv: Fnv::new(),
+        }
+    }
+    /// The checksum of what has been read so far.
+    pub fn checksum(&self) -> String {
+        self.fnv.checksum()
+    }
+}
+impl<R: BufRead> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let nb = self.inner.read(buf)?;
+        self.fnv.write(&buf[..nb]);
+        Ok(nb)
+    }
+}
+impl<R: BufRead> BufRead for ChecksumReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+    fn consume(&mut self, amt: usize) {
+        if let Ok(buf) = self.inner.fill_buf() {
+            self.fnv.write(&buf[..amt.min(buf.len())]);
+        }
+        self.inner.consume(amt);
+    }
+}
+
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// The answer of a part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed_ns: u64,
+}
+
+/// The answers of a day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub input_checksum: String,
+    /// The time to parse the input, for the days not solved while
+    /// parsing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_ns: Option<u64>,
+    /// The time to read and solve the input in a single pass.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_ns: Option<u64>,
+    pub parts: Vec<PartReport>,
+}
+impl DayReport {
+    /// The answers of the two parts.
+    pub fn answers(&self) -> impl Iterator<Item = &Answer> {
+        self.parts.iter().map(|p| &p.answer)
+    }
+    /// The time to parse and solve the two parts.
+    pub fn elapsed(&self) -> Duration {
+        let parts = self.parts.iter().map(|p| p.elapsed_ns).sum::<u64>();
+        let first = self.parse_ns.or(self.solve_ns).unwrap_or(0);
+        Duration::from_nanos(first + parts)
+    }
+}
+
+/// The answers of several days.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub days: Vec<DayReport>,
+    pub elapsed_ns: u64,
+}
+impl Report {
+    pub fn new(days: Vec<DayReport>) -> Self {
+        let elapsed = days.iter().map(DayReport::elapsed).sum();
+        Self {
+            days,
+            elapsed_ns: nanos(elapsed),
+        }
+    }
+}
+
+/// Solve a day with the given parameters, timing the parsing and the
+/// two parts.
+pub fn solve(solver: &dyn Solver, input: &str, params: &[Param]) -> anyhow::Result<DayReport> {
+    let now = Instant::now();
+    let parsed = solver.parse_with(input, params)?;
+    let parse_ns = nanos(now.elapsed());
+    solve_parsed(solver, &*parsed, parse_ns, checksum(input))
+}
+
+/// Solve a day on an input given by a reader, the parsing time
+/// including the reading.
+pub fn solve_reader(
+    solver: &dyn Solver,
+    input: impl BufRead,
+    params: &[Param],
+) -> anyhow::Result<DayReport> {
+    let mut input = ChecksumReader::new(input);
+    let now = Instant::now();
+    let parsed = solver.parse_reader_with(&mut input, params)?;
+    let parse_ns = nanos(now.elapsed());
+    solve_parsed(solver, &*parsed, parse_ns, input.checksum())
+}
+
+fn solve_parsed(
+    solver: &dyn Solver,
+    parsed: &dyn Any,
+    parse_ns: u64,
+    input_checksum: String,
+) -> anyhow::Result<DayReport> {
+    let (parse_ns, solve_ns) = match solver.streaming() {
+        Streaming::SinglePass => (None, Some(parse_ns)),
+        _ => (Some(parse_ns), None),
+    };
+    let mut parts = vec![];
+    for part in 1..=2 {
+        trace!(solver.day(), "part", part = part);
+        let now = Instant::now();
+        let answer = solver.part(parsed, part)?;
+        parts.push(PartReport {
+            part,
+            answer,
+            elapsed_ns: nanos(now.elapsed()),
+        });
+    }
+    Ok(DayReport {
+        day: solver.day(),
+        input_checksum,
+        parse_ns,
+        solve_ns,
+        parts,
+    })
+}
@@ -0,0 +1,263 @@
+//! Shortest path searches over a graph given by a successor function.
+//!
+//! The single source searches ([`bfs`], [`dijkstra`] and [`astar`])
+//! return the [`Paths`] they have found, with the predecessor of each
+//! node, to be able to reconstruct the route. [`all_pairs`] computes
+//! the shortest paths between all the nodes of a small graph.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The paths found by a single source search.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    /// The cost of the reached nodes. When the search stopped at a
+    /// goal, the cost of the nodes not yet expanded may not be optimal.
+    pub costs: HashMap<N, C>,
+    /// The previous node on the best path to each reached node.
+    pub prev: HashMap<N, N>,
+    /// The goal that stopped the search, if any.
+    pub goal: Option<N>,
+}
+impl<N: Eq + Hash + Clone, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            prev: HashMap::new(),
+            goal: None,
+        }
+    }
+    /// The cost to reach `n`, if reached.
+    pub fn cost(&self, n: &N) -> Option<C> {
+        self.costs.get(n).copied()
+    }
+    /// The cost to reach the goal, if reached.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+    /// The path from a start node to `n`, both included.
+    pub fn path(&self, n: &N) -> Option<Vec<N>> {
+        self.costs.get(n)?;
+        let mut path = vec![n.clone()];
+        while let Some(p) = self.prev.get(&path[path.len() - 1]) {
+            path.push(p.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth first search, where every edge costs 1, stopping at the
+/// first node satisfying `is_goal`.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut q = VecDeque::new();
+    for s in starts {
+        if !paths.costs.contains_key(&s) {
+            paths.costs.insert(s.clone(), 0);
+            q.push_back(s);
+        }
+    }
+    while let Some(n) = q.pop_front() {
+        if is_goal(&n) {
+            paths.goal = Some(n);
+            break;
+        }
+        let cost = paths.costs[&n] + 1;
+        for next in successors(&n) {
+            if !paths.costs.contains_key(&next) {
+                paths.costs.insert(next.clone(), cost);
+                paths.prev.insert(next.clone(), n.clone());
+                q.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// A node in the priority queue, the lowest priority first.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<N, C: Ord> Eq for Entry<N, C> {}
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra's algorithm, the successors being given with the cost of
+/// the edge, stopping at the first node satisfying `is_goal`. The zero
+/// cost is `C::default()`.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search: as [`dijkstra`], but the nodes are expanded in the order
+/// of their cost plus `heuristic`, that must never overestimate the
+/// remaining cost to a goal.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut q = BinaryHeap::new();
+    for node in starts {
+        let cost = C::default();
+        paths.costs.insert(node.clone(), cost);
+        let priority = heuristic(&node);
+        q.push(Entry {
+            priority,
+            cost,
+            node,
+        });
+    }
+    while let Some(Entry { cost, node, .. }) = q.pop() {
+        if paths.costs[&node] < cost {
+            continue; // already expanded with a better cost
+        }
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        for (next, c) in successors(&node) {
+            let cost = cost + c;
+            if paths.costs.get(&next).is_some_and(|&cur| cur <= cost) {
+                continue;
+            }
+            paths.costs.insert(next.clone(), cost);
+            paths.prev.insert(next.clone(), node.clone());
+            q.push(Entry {
+                priority: cost + heuristic(&next),
+                cost,
+                node: next,
+            });
+        }
+    }
+    paths
+}
+
+/// The shortest paths between all the pairs of nodes of a graph.
+#[derive(Debug, Clone)]
+pub struct AllPairs<N, C> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    /// `costs[i * len + j]` is the cost from `nodes[i]` to `nodes[j]`.
+    costs: Vec<Option<C>>,
+    /// `next[i * len + j]` is the node after `nodes[i]` on the way to `nodes[j]`.
+    next: Vec<Option<usize>>,
+}
+impl<N: Eq + Hash + Clone, C: Copy> AllPairs<N, C> {
+    fn idx(&self, from: &N, to: &N) -> Option<usize> {
+        Some(self.index[from] * self.nodes.len() + *self.index.get(to)?)
+    }
+    /// The nodes of the graph.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+    /// The cost of the shortest path from `from` to `to`, if any.
+    pub fn cost(&self, from: &N, to: &N) -> Option<C> {
+        self.index.get(from)?;
+        self.costs[self.idx(from, to)?]
+    }
+    /// The shortest path from `from` to `to`, both included.
+    pub fn path(&self, from: &N, to: &N) -> Option<Vec<N>> {
+        self.cost(from, to)?;
+        let mut path = vec![from.clone()];
+        let mut cur = from.clone();
+        while &cur != to {
+            cur = self.nodes[self.next[self.idx(&cur, to)?]?].clone();
+            path.push(cur.clone());
+        }
+        Some(path)
+    }
+}
+
+/// Floyd–Warshall algorithm on `nodes`, the successors being given with
+/// the cost of the edge. The successors not in `nodes` are ignored.
+pub fn all_pairs<N, C, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> AllPairs<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let nodes: Vec<N> = nodes.into_iter().collect();
+    let index: HashMap<N, usize> = nodes.iter().cloned().zip(0..).collect();
+    let len = nodes.len();
+    let mut costs = vec![None; len * len];
+    let mut next = vec![None; len * len];
+    for (i, n) in nodes.iter().enumerate() {
+        costs[i * len + i] = Some(C::default());
+        next[i * len + i] = Some(i);
+        for (s, c) in successors(n) {
+            let Some(&j) = index.get(&s) else { continue };
+            if costs[i * len + j].is_none_or(|cur| c < cur) {
+                costs[i * len + j] = Some(c);
+                next[i * len + j] = Some(j);
+            }
+        }
+    }
+    for k in 0..len {
+        for i in 0..len {
+            let Some(ik) = costs[i * len + k] else {
+                continue;
+            };
+            for j in 0..len {
+                let Some(kj) = costs[k * len + j] else {
+                    continue;
+                };
+                if costs[i * len + j].is_none_or(|cur| ik + kj < cur) {
+                    costs[i * len + j] = Some(ik + kj);
+                    next[i * len + j] = next[i * len + k];
+                }
+            }
+        }
+    }
+    AllPairs {
+        nodes,
+        index,
+        costs,
+        next,
+    }
+}
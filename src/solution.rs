@@ -0,0 +1,188 @@
+//! The [`Solution`] trait implemented by every day.
+
+use crate::params::{self, Param, Params};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::fmt;
+use std::io::BufRead;
+
+/// The answer to a part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    /// A numeric answer, the most common.
+    Num(i64),
+    /// A textual answer, as the crates of day 5 or the screen of day 10.
+    Text(String),
+    /// No answer, as for the second part of day 25.
+    None,
+}
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Num(n) => n.fmt(f),
+            Answer::Text(s) => s.fmt(f),
+            Answer::None => "-".fmt(f),
+        }
+    }
+}
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Num(i64::try_from(n).expect("answer too big"))
+            }
+        }
+    )*};
+}
+answer_from_int!(i32, u32, i64, u64, usize);
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.into())
+    }
+}
+
+/// How a day reads its input from a reader.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Streaming {
+    /// All at once, then parsed as a whole.
+    No,
+    /// Line by line, the lines being folded into a compact input of
+    /// constant size, as the counts of the rounds of day 2, the two
+    /// parts then solving it.
+    Lines,
+    /// Line by line, solving the two parts in a single pass in constant
+    /// memory, as the top elves of day 1 for inputs of any size. The
+    /// parsing is then the solving, the parts only giving the answers,
+    /// and its time is reported as such.
+    SinglePass,
+}
+
+/// The solution of a day, split into parsing and the two parts.
+pub trait Solution {
+    /// The day number, in `1..=25`.
+    const DAY: u8;
+    /// The parsed input, shared by the two parts.
+    type Input;
+    /// The constants of the puzzle that can be changed, `()` if none.
+    type Params: Params;
+    /// How [`Solution::parse_reader`] reads the input.
+    const STREAMING: Streaming = Streaming::No;
+    /// Parse the puzzle input.
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+    /// Parse the puzzle input with the given parameters, the days
+    /// without parameters ignoring them.
+    fn parse_with(input: &str, _params: &Self::Params) -> anyhow::Result<Self::Input> {
+        Self::parse(input)
+    }
+    /// Parse the puzzle input from a reader, read whole by default.
+    fn parse_reader(input: &mut dyn BufRead) -> anyhow::Result<Self::Input> {
+        let mut s = String::new();
+        input.read_to_string(&mut s)?;
+        Self::parse(&s)
+    }
+    /// Parse the puzzle input from a reader with the given parameters.
+    /// By default, the streaming days ignore them, the others reading
+    /// the input whole for [`Solution::parse_with`].
+    fn parse_reader_with(
+        input: &mut dyn BufRead,
+        params: &Self::Params,
+    ) -> anyhow::Result<Self::Input> {
+        if Self::STREAMING != Streaming::No {
+            return Self::parse_reader(input);
+        }
+        let mut s = String::new();
+        input.read_to_string(&mut s)?;
+        Self::parse_with(&s, params)
+    }
+    /// The invariants of the input that the puzzle guarantees but the
+    /// parsing doesn't check, as messages describing their violations,
+    /// given the input and its parsed form.
+    fn validate(_input: &str, _parsed: &Self::Input) -> Vec<String> {
+        vec![]
+    }
+    /// Solve the first part.
+    fn part1(input: &Self::Input) -> anyhow::Result<Answer>;
+    /// Solve the second part.
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer>;
+}
+
+/// A type erased [`Solution`], to be able to select a day at runtime.
+pub trait Solver: Sync {
+    /// The day number, in `1..=25`.
+    fn day(&self) -> u8;
+    /// The names of the parameters and their default values.
+    fn params(&self) -> Vec<(&'static str, String)>;
+    /// Parse the puzzle input, with the default parameters.
+    fn parse(&self, input: &str) -> anyhow::Result<Box<dyn Any>> {
+        self.parse_with(input, &[])
+    }
+    /// Parse the puzzle input, changing the given parameters.
+    fn parse_with(&self, input: &str, params: &[Param]) -> anyhow::Result<Box<dyn Any>>;
+    /// How [`Solver::parse_reader`] reads the input.
+    fn streaming(&self) -> Streaming;
+    /// Parse the puzzle input from a reader, with the default parameters.
+    fn parse_reader(&self, input: &mut dyn BufRead) -> anyhow::Result<Box<dyn Any>> {
+        self.parse_reader_with(input, &[])
+    }
+    /// Parse the puzzle input from a reader, changing the given
+    /// parameters.
+    fn parse_reader_with(
+        &self,
+        input: &mut dyn BufRead,
+        params: &[Param],
+    ) -> anyhow::Result<Box<dyn Any>>;
+    /// Parse the input and check its invariants, returning their
+    /// violations.
+    fn validate(&self, input: &str) -> anyhow::Result<Vec<String>>;
+    /// Solve the given part (1 or 2) on an input returned by [`Solver::parse`].
+    fn part(&self, input: &dyn Any, part: u8) -> anyhow::Result<Answer>;
+    /// Parse the input and solve the two parts.
+    fn solve(&self, input: &str) -> anyhow::Result<[Answer; 2]> {
+        let input = self.parse(input)?;
+        Ok([self.part(&*input, 1)?, self.part(&*input, 2)?])
+    }
+}
+impl<S: Solution + Sync> Solver for S
+where
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+    fn params(&self) -> Vec<(&'static str, String)> {
+        S::Params::default().values()
+    }
+    fn parse_with(&self, input: &str, params: &[Param]) -> anyhow::Result<Box<dyn Any>> {
+        Ok(Box::new(params::parse::<S>(input, params)?))
+    }
+    fn streaming(&self) -> Streaming {
+        S::STREAMING
+    }
+    fn parse_reader_with(
+        &self,
+        input: &mut dyn BufRead,
+        params: &[Param],
+    ) -> anyhow::Result<Box<dyn Any>> {
+        let params = params::build(S::DAY, params)?;
+        Ok(Box::new(S::parse_reader_with(input, &params)?))
+    }
+    fn validate(&self, input: &str) -> anyhow::Result<Vec<String>> {
+        Ok(S::validate(input, &S::parse(input)?))
+    }
+    fn part(&self, input: &dyn Any, part: u8) -> anyhow::Result<Answer> {
+        let Some(input) = input.downcast_ref::<S::Input>() else {
+            anyhow::bail!("input of day {} expected", S::DAY)
+        };
+        match part {
+            1 => S::part1(input),
+            2 => S::part2(input),
+            _ => anyhow::bail!("no part {}", part),
+        }
+    }
+}
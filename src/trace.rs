@@ -0,0 +1,72 @@
+//! Structured events logged by the days, for `--trace`.
+//!
+//! An event is a line of `key=value` fields, starting with the day and
+//! the name of the event, as
+//!
+//! ```text
+//! day=5 event=move nb=3 from=1 to=3 moved=ZND
+//! day=21 event=inverse monkey=root op=- equal=0 next=pppw next_equal=150
+//! ```
+//!
+//! the values containing spaces being quoted. Tracing is off by default:
+//! the [`trace!`](crate::trace!) macro then only checks a flag, the
+//! fields not even being evaluated.
+
+use std::fmt::Display;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+use std::sync::Mutex;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static OUTPUT: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+/// Log the events to `output`, or stop tracing with `None`, flushing
+/// the previous output.
+pub fn set_output(output: Option<Box<dyn Write + Send>>) {
+    let mut cur = OUTPUT.lock().unwrap_or_else(|e| e.into_inner());
+    ENABLED.store(output.is_some(), Relaxed);
+    if let Some(mut prev) = std::mem::replace(&mut *cur, output) {
+        let _ = prev.flush();
+    }
+}
+
+/// Whether the events are logged.
+#[inline]
+pub fn enabled() -> bool {
+    ENABLED.load(Relaxed)
+}
+
+/// Log an event, use [`trace!`](crate::trace!) instead.
+#[cold]
+pub fn event(day: u8, event: &str, fields: &[(&str, &dyn Display)]) {
+    let mut line = format!("day={} event={}", day, event);
+    for (key, value) in fields {
+        let value = value.to_string();
+        if value.is_empty() || value.contains([' ', '"', '=']) {
+            line += &format!(" {}={:?}", key, value);
+        } else {
+            line += &format!(" {}={}", key, value);
+        }
+    }
+    line.push('\n');
+    let mut output = OUTPUT.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(output) = &mut *output {
+        // a broken trace must not stop the solving
+        let _ = output.write_all(line.as_bytes());
+    }
+}
+
+/// Log an event of a day if tracing is on, as
+/// `trace!(5, "move", nb = m.nb, from = m.from)`.
+#[macro_export]
+macro_rules! trace {
+    ($day:expr, $event:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled() {
+            $crate::trace::event(
+                $day,
+                $event,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Display)),*],
+            );
+        }
+    };
+}
@@ -0,0 +1,107 @@
+//! Check the animations of the simulations.
+
+use aoc2022::animate::{self, Options, Viewport};
+
+fn render(day: u8, part: u8, options: &Options) -> (usize, String) {
+    let input = std::fs::read_to_string(format!("data/test{}.txt", day)).unwrap();
+    let mut out = vec![];
+    let nb = animate::render(day, part, &input, options, &mut out).unwrap();
+    (nb, String::from_utf8(out).unwrap())
+}
+
+#[test]
+fn day14_sand() {
+    let (nb, out) = render(14, 1, &Options::default());
+    assert_eq!(nb, 24);
+    let last = out.split("\n\n").nth(23).unwrap();
+    let expected = "\
+units of sand: 24
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.";
+    assert_eq!(last, expected);
+    assert_eq!(render(14, 2, &Options::default()).0, 93);
+}
+
+#[test]
+fn frames_of_the_days() {
+    let options = Options::default();
+    assert_eq!(render(23, 1, &options).0, 11);
+    assert_eq!(render(23, 2, &options).0, 20);
+    assert_eq!(render(24, 1, &options).0, 18 + 1);
+    let (nb, out) = render(24, 2, &options);
+    assert_eq!(nb, 54 + 1);
+    assert!(
+        out.ends_with("minute 54\n#.######\n#>2.<.<#\n#.2v^2<#\n#>..>2>#\n#<....>#\n######E#\n\n")
+    );
+    let options = Options {
+        max_frames: Some(10),
+        ..Options::default()
+    };
+    assert_eq!(render(17, 1, &options).0, 10);
+
+    // the number of rocks of the part
+    let rocks = |p: &str| Options {
+        params: vec![p.parse().unwrap()],
+        ..Options::default()
+    };
+    let (nb, out) = render(17, 2, &rocks("rocks2=3"));
+    assert_eq!((nb, out), render(17, 1, &rocks("rocks1=3")));
+    assert!(nb > 3);
+    let options = Options {
+        max_frames: Some(nb + 1),
+        ..rocks("rocks1=3")
+    };
+    assert_eq!(render(17, 2, &options).0, nb + 1);
+}
+
+#[test]
+fn viewports() {
+    let v: Viewport = "3x4@1,501".parse().unwrap();
+    assert_eq!(v.size, Some((3, 4)));
+    assert_eq!(v.at, Some((1, 501)));
+    let options = Options {
+        viewport: v,
+        max_frames: Some(1),
+        ..Options::default()
+    };
+    assert_eq!(
+        render(14, 1, &options).1,
+        "units of sand: 1\n... \n... \n... \n\n"
+    );
+    assert!("3x".parse::<Viewport>().is_err());
+    assert!("3x4@1".parse::<Viewport>().is_err());
+
+    // following the falling rock of day 17
+    let options = Options {
+        viewport: "3x9".parse().unwrap(),
+        max_frames: Some(1),
+        ..Options::default()
+    };
+    assert_eq!(
+        render(17, 1, &options).1,
+        "rock 1, height 0\n|.......|\n|.......|\n|..@@@@.|\n\n"
+    );
+}
+
+#[test]
+fn in_the_terminal() {
+    let options = Options {
+        fps: Some(0.),
+        max_frames: Some(2),
+        ..Options::default()
+    };
+    let (nb, out) = render(23, 1, &options);
+    assert_eq!(nb, 2);
+    assert!(out.starts_with("\x1b[2J\x1b[Hinitial state\x1b[K\n"));
+    assert_eq!(out.matches("\x1b[H").count(), 2);
+    let input = std::fs::read_to_string("data/test5.txt").unwrap();
+    assert!(animate::render(5, 1, &input, &options, &mut vec![]).is_err());
+}
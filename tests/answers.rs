@@ -0,0 +1,45 @@
+//! Check the answer store.
+
+use aoc2022::answers::Store;
+use aoc2022::days;
+use aoc2022::report;
+use aoc2022::solution::Answer;
+
+fn day1_example() -> report::DayReport {
+    let input = std::fs::read_to_string("data/test1.txt").unwrap();
+    report::solve(days::get(1).unwrap(), &input, &[]).unwrap()
+}
+
+#[test]
+fn record_save_load_and_verify() {
+    let r = day1_example();
+    let mut store = Store::default();
+    assert_eq!(store.verify(&r).len(), 2);
+    store.record(&r);
+    assert!(store.verify(&r).is_empty());
+
+    let path = std::env::temp_dir().join(format!("aoc2022-answers-{}.json", std::process::id()));
+    let path = path.to_str().unwrap();
+    store.save(path).unwrap();
+    let loaded = Store::load(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert!(loaded.verify(&r).is_empty());
+    assert_eq!(
+        loaded.get(1, 2, &r.input_checksum),
+        Some(&Answer::Num(45000))
+    );
+}
+
+#[test]
+fn mismatch_as_diff() {
+    let mut r = day1_example();
+    let mut store = Store::default();
+    store.record(&r);
+    r.parts[1].answer = Answer::Num(42);
+    let mismatches = store.verify(&r);
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(
+        mismatches[0].to_string(),
+        format!("day 1 part 2 on {}:\n- 45000\n+ 42\n", r.input_checksum)
+    );
+}
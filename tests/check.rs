@@ -0,0 +1,67 @@
+//! Check the validation of the inputs and the guessing of their day.
+
+use aoc2022::days;
+
+fn violations(day: u8, input: &str) -> Vec<String> {
+    days::get(day).unwrap().validate(input).unwrap()
+}
+
+#[test]
+fn each_input_has_a_single_day() {
+    for kind in ["test", "input"] {
+        for day in 1..=days::NB_DAYS {
+            let input = std::fs::read_to_string(format!("data/{}{}.txt", kind, day)).unwrap();
+            assert_eq!(days::guess(&input), [day], "{}{}", kind, day);
+        }
+    }
+    assert_eq!(days::guess("not an input\n"), [0u8; 0]);
+}
+
+#[test]
+fn invariants() {
+    let test = |day| std::fs::read_to_string(format!("data/test{}.txt", day)).unwrap();
+
+    let trees: String = test(8)
+        .lines()
+        .take(4)
+        .map(|l| format!("{}\n", l))
+        .collect();
+    assert_eq!(
+        violations(8, &trees),
+        ["a square grid expected, got 4x5 trees"]
+    );
+
+    let map = test(12).replacen('a', "E", 1).replacen('b', "S", 1);
+    assert_eq!(
+        violations(12, &map),
+        [
+            "exactly one start position `S` expected, got 2",
+            "exactly one best signal position `E` expected, got 2",
+        ]
+    );
+
+    assert_eq!(
+        violations(20, "1\n2\n3\n"),
+        ["exactly one 0 expected, got 0"]
+    );
+    assert_eq!(
+        violations(20, "0\n2\n0\n"),
+        ["exactly one 0 expected, got 2"]
+    );
+
+    // 6 faces of 2x2 tiles, but not aligned
+    let faces = "  ....\n  ....\n........\n........\n\n1R1\n";
+    assert!(violations(22, faces).is_empty());
+    let shifted = " ....\n ....\n........\n........\n\n1R1\n";
+    assert_eq!(
+        violations(22, shifted),
+        [
+            "a face of 2x2 tiles expected at line 1, column 1, got 2 tiles",
+            "a face of 2x2 tiles expected at line 1, column 5, got 2 tiles",
+        ]
+    );
+    assert_eq!(
+        violations(22, "....\n\n1R1\n"),
+        ["6 equal square faces expected, got 4 tiles"]
+    );
+}
@@ -0,0 +1,66 @@
+//! Check the cycle detection against a plain simulation.
+
+use aoc2022::cycle::{self, Cycle, Method};
+
+/// A pseudo random sequence, cycling after a while, with the sum of
+/// its values as metric.
+#[derive(Clone)]
+struct Seq {
+    x: u64,
+    sum: i64,
+}
+fn step(s: &mut Seq) {
+    s.x = (s.x * s.x + 1) % 1009;
+    s.sum += s.x as i64;
+}
+const INIT: Seq = Seq { x: 3, sum: 0 };
+
+fn simulate(nb: usize) -> i64 {
+    let mut s = INIT;
+    for _ in 0..nb {
+        step(&mut s);
+    }
+    s.sum
+}
+
+#[test]
+fn all_methods_find_the_same_cycle() {
+    let expected = cycle::hashed(&INIT, step, |s| s.x, 10_000).unwrap();
+    assert!(expected.start > 0 && expected.period > 1, "{:?}", expected);
+    assert_eq!(cycle::floyd(&INIT, step, |s| s.x, 10_000), Some(expected));
+    assert_eq!(cycle::brent(&INIT, step, |s| s.x, 10_000), Some(expected));
+    assert_eq!(cycle::hashed(&INIT, step, |s| s.x, 2), None);
+}
+
+#[test]
+fn extrapolate_like_a_simulation() {
+    for method in [Method::Floyd, Method::Brent, Method::Hash] {
+        for target in [0, 1, 7, 100, 12345] {
+            let e = cycle::extrapolate(method, &INIT, step, |s| s.x, |s| s.sum, target);
+            assert_eq!(e.value, simulate(target), "{:?} {}", method, target);
+        }
+    }
+}
+
+#[test]
+fn common_periods() {
+    assert_eq!(cycle::gcd(4, 6), 2);
+    assert_eq!(cycle::gcd(0, 5), 5);
+    assert_eq!(cycle::lcm(4, 6), 12);
+    assert_eq!(cycle::lcm(25, 120), 600);
+    assert_eq!(cycle::lcm(7, 0), 0);
+}
+
+#[test]
+fn periodic_from_the_start() {
+    // As the blizzards of day 24, back to their initial positions.
+    let step = |x: &mut u32| *x = (*x + 1) % 12;
+    let c = cycle::brent(&0, step, |&x| x, 100);
+    assert_eq!(
+        c,
+        Some(Cycle {
+            start: 0,
+            period: 12
+        })
+    );
+}
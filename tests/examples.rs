@@ -0,0 +1,80 @@
+//! Run every day on the examples of the puzzles, checking the
+//! published answers stored in `data/examples.json`.
+
+use aoc2022::days;
+use aoc2022::params::Param;
+use aoc2022::solution::Answer;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Example {
+    day: u8,
+    /// Defaults to `data/test{day}.txt`.
+    input: Option<String>,
+    /// The parameters of the example, as `y=10`.
+    #[serde(default)]
+    params: Vec<String>,
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+    /// Why an answer is missing.
+    #[allow(dead_code)]
+    note: Option<String>,
+}
+
+fn check(day: u8) {
+    let manifest = std::fs::read_to_string("data/examples.json").unwrap();
+    let examples: Vec<Example> = serde_json::from_str(&manifest).unwrap();
+    let examples: Vec<_> = examples.into_iter().filter(|e| e.day == day).collect();
+    assert!(!examples.is_empty(), "no example for day {}", day);
+
+    let solver = days::get(day).unwrap();
+    for e in examples {
+        let path = e.input.unwrap_or_else(|| format!("data/test{}.txt", day));
+        let input = std::fs::read_to_string(&path).unwrap();
+        let params: Vec<Param> = e.params.iter().map(|p| p.parse().unwrap()).collect();
+        let parsed = solver.parse_with(&input, &params).unwrap();
+        for (part, expected) in [(1, e.part1), (2, e.part2)] {
+            let Some(expected) = expected else { continue };
+            let answer = solver.part(&*parsed, part).unwrap();
+            assert_eq!(answer, expected, "day {} part {} on {}", day, part, path);
+        }
+    }
+}
+
+macro_rules! examples {
+    ($($(#[$attr:meta])* $name:ident: $day:expr,)*) => {$(
+        #[test]
+        $(#[$attr])*
+        fn $name() {
+            check($day);
+        }
+    )*};
+}
+
+examples! {
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+    day16: 16,
+    day17: 17,
+    day18: 18,
+    day19: 19,
+    day20: 20,
+    day21: 21,
+    day22: 22,
+    day23: 23,
+    day24: 24,
+    day25: 25,
+}
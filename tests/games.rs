@@ -0,0 +1,88 @@
+//! Check the cyclic games of day 2 with other numbers of moves.
+
+use aoc2022::days::day02::{Game, Outcome};
+use aoc2022::days::{self, day02};
+use aoc2022::params::Param;
+use aoc2022::solution::Answer;
+
+/// Rock, Spock, paper, lizard, scissors.
+fn rpsls() -> Game {
+    let params = day02::Params {
+        them: "ABCDE".into(),
+        us: "VWXYZ".into(),
+        scores: "1,2,3,4,5".parse().unwrap(),
+        ..Default::default()
+    };
+    Game::new(&params).unwrap()
+}
+
+#[test]
+fn rock_paper_scissors_lizard_spock() {
+    let [rock, spock, paper, lizard, scissors] = [0, 1, 2, 3, 4];
+    let wins = [
+        (scissors, paper),
+        (paper, rock),
+        (rock, lizard),
+        (lizard, spock),
+        (spock, scissors),
+        (scissors, lizard),
+        (lizard, paper),
+        (paper, spock),
+        (spock, rock),
+        (rock, scissors),
+    ];
+    let game = rpsls();
+    for them in 0..5 {
+        for us in 0..5 {
+            let expected = match () {
+                _ if them == us => Outcome::Draw,
+                _ if wins.contains(&(us, them)) => Outcome::Win,
+                _ => Outcome::Lose,
+            };
+            assert_eq!(game.outcome(them, us), expected, "{} against {}", us, them);
+        }
+        // the shifts from losing the most to winning the most
+        let shifted: Vec<_> = (0..5)
+            .map(|i| game.outcome(them, game.shifted(them, i)))
+            .collect();
+        use Outcome::*;
+        assert_eq!(shifted, [Lose, Lose, Draw, Win, Win]);
+    }
+}
+
+#[test]
+fn strategy_guide() {
+    let solver = days::get(2).unwrap();
+    let params: Vec<Param> = [
+        "them=ABCDE",
+        "us=VWXYZ",
+        "scores=1,2,3,4,5",
+        "outcomes=0,1,2",
+    ]
+    .iter()
+    .map(|p| p.parse().unwrap())
+    .collect();
+    // paper against rock, lizard against spock, spock against spock
+    let parsed = solver.parse_with("A X\nB Y\nB W\n", &params).unwrap();
+    assert_eq!(
+        solver.part(&*parsed, 1).unwrap(),
+        Answer::Num(3 + 2 + 4 + 2 + 2 + 1)
+    );
+    // to draw with rock, to win against spock by paper and lose by rock
+    assert_eq!(
+        solver.part(&*parsed, 2).unwrap(),
+        Answer::Num(1 + 1 + 3 + 2 + 1)
+    );
+
+    let e = solver.parse_with("A Q\n", &params).err().unwrap();
+    assert!(e.to_string().contains("`V`, `W`, `X`, `Y` or `Z`"), "{}", e);
+    for bad in [
+        &["them=ABCD"][..],
+        &["us=XXZ"],
+        &["scores=1,2"],
+        &["outcomes=0,3"],
+    ] {
+        let params: Vec<Param> = bad.iter().map(|p| p.parse().unwrap()).collect();
+        assert!(solver.parse_with("A X\n", &params).is_err(), "{:?}", bad);
+    }
+}
@@ -0,0 +1,63 @@
+//! Check that the generated inputs are valid for every day.
+
+use aoc2022::days::{self, NB_DAYS};
+use aoc2022::generate::{self, Rng};
+use aoc2022::report;
+
+/// Small enough to be solved quickly in debug.
+const SIZES: [usize; 3] = [1, 3, 8];
+
+#[test]
+fn generated_inputs_are_solved() {
+    for day in 1..=NB_DAYS {
+        // the search space of day 15 is too big for a debug build
+        let parts_solved = day != 15;
+        for (seed, size) in SIZES.into_iter().enumerate() {
+            let input = generate::generate(day, size, seed as u64).unwrap();
+            let solver = days::get(day).unwrap();
+            let parsed = solver.parse(&input);
+            let parsed = parsed.unwrap_or_else(|e| panic!("day {} size {}: {:?}", day, size, e));
+            for part in (1..=2).filter(|_| parts_solved) {
+                if let Err(e) = solver.part(&*parsed, part) {
+                    panic!(
+                        "day {} part {} size {}: {:?}\n{}",
+                        day, part, size, e, input
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generation_is_deterministic() {
+    for day in 1..=NB_DAYS {
+        let input = |seed| generate::generate(day, 8, seed).unwrap();
+        assert_eq!(input(1), input(1), "day {}", day);
+        assert_ne!(
+            report::checksum(&input(1)),
+            report::checksum(&input(2)),
+            "day {}",
+            day
+        );
+    }
+}
+
+#[test]
+fn rng_ranges() {
+    let mut rng = Rng::new(42);
+    for _ in 0..1000 {
+        assert!((-3..=3).contains(&rng.range(-3, 3)));
+        assert!(rng.below(5) < 5);
+    }
+    let mut v: Vec<_> = (0..10).collect();
+    rng.shuffle(&mut v);
+    v.sort_unstable();
+    assert_eq!(v, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn unknown_day() {
+    assert!(generate::generate(26, 10, 0).is_err());
+    assert!(generate::generate(11, 9, 0).is_err());
+}
@@ -0,0 +1,48 @@
+//! Check the shared grid.
+
+use aoc2022::grid::Grid;
+use aoc2022::parse;
+
+fn elves(s: &str) -> Grid<bool> {
+    Grid::parse(parse::lines(s), "`.` or `#`", |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })
+    .unwrap()
+}
+
+#[test]
+fn parse_and_display() {
+    let g = elves("..#\n#..\n");
+    assert_eq!((g.width(), g.height()), (3, 2));
+    assert_eq!(g.to_string(), "..#\n#..\n");
+
+    let e = Grid::parse(parse::lines("ab\nc\n"), "a letter", Some).unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (2, "a line of 2 characters"));
+    let g = Grid::parse_padded(parse::lines("ab\nc\n"), ' ', "a letter", Some).unwrap();
+    assert_eq!(g.to_string(), "ab\nc \n");
+}
+
+#[test]
+fn neighbours_and_rays() {
+    let g = elves("...\n...\n...\n");
+    assert_eq!(g.neighbours4((0, 0)).count(), 2);
+    assert_eq!(g.neighbours8((1, 1)).count(), 8);
+    assert_eq!(g.neighbours8((2, 1)).count(), 5);
+    let ray: Vec<_> = g.ray((2, 0), (-1, 1)).collect();
+    assert_eq!(ray, [(2, 0), (1, 1), (0, 2)]);
+    assert_eq!(g.column(1).count(), 3);
+}
+
+#[test]
+fn grow_and_trim_keep_positions() {
+    let mut g = elves("#.\n..\n");
+    g.grow_to((-1, 3), false);
+    assert_eq!((g.width(), g.height()), (4, 3));
+    assert_eq!(g.coord((0, 0)), Some((1, 0)));
+    assert!(g[(1, 0)]);
+    g.trim(|&c| !c);
+    assert_eq!(g.to_string(), "#\n");
+    assert_eq!(g.pos((0, 0)), (0, 0));
+}
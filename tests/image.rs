@@ -0,0 +1,79 @@
+//! Check the images of the days.
+
+use aoc2022::image::{self, Image, BLACK, WHITE};
+
+fn export(day: u8, part: u8) -> Image {
+    let input = std::fs::read_to_string(format!("data/test{}.txt", day)).unwrap();
+    image::export(day, part, &input).unwrap()
+}
+
+fn count<T: PartialEq>(cells: &[T], c: T) -> usize {
+    cells.iter().filter(|&x| *x == c).count()
+}
+
+#[test]
+fn pbm_and_ppm() {
+    let Image::Bitmap(visible) = export(8, 1) else {
+        panic!("a bitmap expected");
+    };
+    assert_eq!(count(visible.cells(), true), 21);
+    let mut out = vec![];
+    Image::Bitmap(visible.clone()).write(&mut out, 1).unwrap();
+    assert_eq!(&out[..7], b"P4\n5 5\n");
+    // 5 bits per row, padded to a byte
+    assert_eq!(&out[7..], [0xf8, 0xe8, 0xd8, 0xa8, 0xf8]);
+    let mut out = vec![];
+    Image::Bitmap(visible).write(&mut out, 2).unwrap();
+    assert_eq!(&out[..9], b"P4\n10 10\n");
+    assert_eq!(&out[9..13], [0xff, 0xc0, 0xff, 0xc0]);
+
+    let mut out = vec![];
+    export(14, 1).write(&mut out, 3).unwrap();
+    assert!(out.starts_with(b"P6\n30 30\n255\n"));
+    assert_eq!(
+        (export(8, 1).extension(), export(14, 1).extension()),
+        ("pbm", "ppm")
+    );
+    assert_eq!(out.len(), 13 + 30 * 30 * 3);
+}
+
+#[test]
+fn images_of_the_days() {
+    let Image::Pixmap(distances) = export(12, 1) else {
+        panic!("a pixmap expected");
+    };
+    assert_eq!((distances.width(), distances.height()), (8, 5));
+    assert_eq!(count(distances.cells(), WHITE), 31 + 1);
+    assert!(!distances.cells().contains(&BLACK));
+    let Image::Pixmap(distances) = export(12, 2) else {
+        panic!("a pixmap expected");
+    };
+    assert_eq!(count(distances.cells(), WHITE), 29 + 1);
+
+    let Image::Pixmap(sand) = export(14, 1) else {
+        panic!("a pixmap expected");
+    };
+    assert_eq!(count(sand.cells(), [230, 190, 90]), 24);
+
+    // the air trapped at 2,2,5
+    let Image::Pixmap(sections) = export(18, 1) else {
+        panic!("a pixmap expected");
+    };
+    assert_eq!((sections.width(), sections.height()), (8 * 6 - 1, 5));
+    assert_eq!(count(sections.cells(), BLACK), 1);
+    assert_eq!(sections[(2, 5 * 6 + 2)], BLACK);
+
+    let Image::Bitmap(elves) = export(23, 2) else {
+        panic!("a bitmap expected");
+    };
+    assert_eq!(count(elves.cells(), true), 22);
+    assert!(image::export(5, 1, "").is_err());
+}
+
+#[test]
+fn heat() {
+    assert_eq!(image::heat(0.), [0, 0, 255]);
+    assert_eq!(image::heat(0.5), [0, 255, 0]);
+    assert_eq!(image::heat(1.), [255, 0, 0]);
+    assert_eq!(image::heat(2.), [255, 0, 0]);
+}
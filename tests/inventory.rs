@@ -0,0 +1,102 @@
+//! Check the inventory of the calories of day 1.
+
+use aoc2022::days::day01::inventory::{self, Elf, Stats};
+use aoc2022::generate::Rng;
+
+fn elves(calories: &[u64]) -> Vec<Elf> {
+    (1..)
+        .zip(calories)
+        .map(|(no, &calories)| Elf { no, calories })
+        .collect()
+}
+
+#[test]
+fn read_numbers_the_elves() {
+    let input = "\n5\n\n1\n2\n\n\n\n7\n";
+    let read = inventory::read(&mut input.as_bytes()).unwrap();
+    assert_eq!(read, elves(&[5, 3, 7]));
+    assert!(inventory::read(&mut "1\n-2\n".as_bytes()).is_err());
+}
+
+#[test]
+fn top_with_ties() {
+    let top = inventory::top(elves(&[5, 5, 3, 5, 7, 5]), 2);
+    let elf = |no, calories| Elf { no, calories };
+    assert_eq!(top.elves(), [elf(5, 7), elf(1, 5)]);
+    assert_eq!(top.ties(), [elf(2, 5), elf(4, 5), elf(6, 5)]);
+    // no more ties when the last of the top carries more
+    let top = inventory::top(elves(&[5, 5, 6, 6]), 2);
+    assert_eq!(top.elves().iter().map(|e| e.no).collect::<Vec<_>>(), [3, 4]);
+    assert!(top.ties().is_empty());
+    assert!(inventory::top(elves(&[1, 2]), 0).elves().is_empty());
+
+    // the same as sorting everything
+    let mut rng = Rng::new(0);
+    for n in 0..20 {
+        let calories: Vec<_> = (0..100).map(|_| rng.range(0, 30) as u64).collect();
+        let mut sorted = elves(&calories);
+        sorted.sort_by_key(|e| (std::cmp::Reverse(e.calories), e.no));
+        let top = inventory::top(elves(&calories), n);
+        assert_eq!(top.elves(), sorted[..n], "top {}", n);
+        let last = sorted[..n].last().map(|e| e.calories);
+        let ties: Vec<_> = sorted[n..]
+            .iter()
+            .filter(|e| Some(e.calories) == last)
+            .copied()
+            .collect();
+        let mut expected = ties;
+        expected.sort_by_key(|e| e.no);
+        assert_eq!(top.ties(), expected, "ties of the top {}", n);
+    }
+}
+
+#[test]
+fn stats() {
+    assert_eq!(Stats::new(&[]), None);
+    let stats = Stats::new(&elves(&[6000, 4000, 11000, 24000, 10000])).unwrap();
+    assert_eq!(
+        (stats.nb_elves, stats.total, stats.mean),
+        (5, 55000, 11000.)
+    );
+    assert_eq!(
+        (stats.min(), stats.max(), stats.median()),
+        (4000, 24000, 10000.)
+    );
+    assert_eq!(stats.percentile(25.), 6000.);
+    assert_eq!(stats.percentile(90.), 18800.);
+    assert_eq!(Stats::new(&elves(&[1, 2])).unwrap().median(), 1.5);
+
+    let stats = Stats::new(&elves(&[0, 1, 2, 3, 9, 10])).unwrap();
+    assert_eq!(stats.histogram(2), [(0..6, 4), (6..12, 2)]);
+    assert_eq!(
+        stats.histogram(4),
+        [(0..3, 3), (3..6, 1), (6..9, 0), (9..12, 2)]
+    );
+    let stats = Stats::new(&elves(&[7, 7])).unwrap();
+    assert_eq!(stats.histogram(10), [(7..8, 2)]);
+}
+
+#[test]
+fn report() {
+    let mut out = vec![];
+    inventory::report(&elves(&[5, 5, 3, 5, 7]), 3, &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "\
+Elves: 5, carrying 25 calories
+Mean: 5.0, median: 5.0, min: 3, max: 7
+Percentiles: 10%: 3.8, 25%: 5.0, 75%: 5.0, 90%: 6.2, 99%: 6.9
+Top 3:
+    1. elf 5: 7
+    2. elf 1: 5 (tie)
+    3. elf 2: 5 (tie)
+Left out with as much: elf 4
+Histogram:
+           3..4     1 ##############
+           4..5     0
+           5..6     3 ########################################
+           6..7     0
+           7..8     1 ##############
+"
+    );
+}
@@ -0,0 +1,66 @@
+//! Check that running on several threads gives the same results.
+
+use aoc2022::days::{self, day15};
+use aoc2022::generate::Rng;
+use aoc2022::parallel;
+
+#[test]
+fn map_keeps_the_order() {
+    parallel::set_threads(4);
+    let items: Vec<u64> = (0..1000).collect();
+    let squares = parallel::map(&items, |i| i * i);
+    assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<_>>());
+    assert!(parallel::map(&[] as &[u64], |i| i * i).is_empty());
+}
+
+#[test]
+fn nested_jobs_on_a_single_thread() {
+    parallel::set_threads(4);
+    assert_eq!(parallel::threads(), 4);
+    let items: Vec<u64> = (0..8).collect();
+    let inner = parallel::map(&items, |_| {
+        let inner = parallel::map(&items, |i| i + 1);
+        (parallel::threads(), inner.iter().sum::<u64>())
+    });
+    assert_eq!(inner, vec![(1, 36); 8]);
+}
+
+#[test]
+fn find_map_first_is_the_first() {
+    parallel::set_threads(4);
+    let items: Vec<u64> = (0..1000).collect();
+    let found = parallel::find_map_first(&items, |&i| (i % 97 == 96).then_some(i));
+    assert_eq!(found, Some(96));
+    assert_eq!(parallel::find_map_first(&items, |_| None::<u64>), None);
+}
+
+#[test]
+fn days_on_several_threads() {
+    parallel::set_threads(4);
+    for day in [16, 19] {
+        let input = std::fs::read_to_string(format!("data/test{}.txt", day)).unwrap();
+        let solver = days::get(day).unwrap();
+        let parsed = solver.parse(&input).unwrap();
+        let answers: Vec<_> = (1..=2).map(|p| solver.part(&*parsed, p).unwrap()).collect();
+        let expected = match day {
+            16 => [1651.into(), 1707.into()],
+            _ => [33.into(), (56 * 62).into()],
+        };
+        assert_eq!(answers, expected, "day {}", day);
+    }
+
+    // 4 sensors in the diagonals, just not seeing the distress beacon,
+    // in another block of rows than the first one
+    let mut rng = Rng::new(0);
+    for _ in 0..5 {
+        let space = 20_000;
+        let distress = (rng.range(0, space), rng.range(5000, space));
+        let sensors: Vec<_> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+            .map(|(dx, dy)| {
+                let s = (distress.0 + dx * space, distress.1 + dy * space);
+                (s, (s.0 - dx * (2 * space - 1), s.1))
+            })
+            .into();
+        assert_eq!(day15::distress(&sensors, space), Some(distress));
+    }
+}
@@ -0,0 +1,63 @@
+//! Check the parameters of the days.
+
+use aoc2022::days::{self, day10};
+use aoc2022::params::{self, List, Param, Params};
+use aoc2022::report;
+
+fn param(s: &str) -> Param {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse_and_build() {
+    assert_eq!(
+        param("15.y=10"),
+        Param {
+            day: Some(15),
+            key: "y".into(),
+            value: "10".into()
+        }
+    );
+    assert_eq!(param("cycles=1,2").day, None);
+    for bad in ["y", "=10", "x.y=10", "15.=10"] {
+        assert!(bad.parse::<Param>().is_err(), "{:?}", bad);
+    }
+
+    let p: day10::Params = params::build(10, &[param("cycles=1, 3"), param("9.x=1")]).unwrap();
+    assert_eq!(p.cycles, List(vec![1, 3]));
+    assert_eq!(p.values(), [("cycles", "1,3".to_string())]);
+    let e = params::build::<day10::Params>(10, &[param("x=1")]).unwrap_err();
+    assert_eq!(
+        format!("{:#}", e),
+        "bad parameter of day 10: unknown parameter \"x\", expected cycles"
+    );
+    assert!(params::build::<()>(1, &[param("x=1")]).is_err());
+    assert!(params::build::<day10::Params>(10, &[param("cycles=a")]).is_err());
+}
+
+#[test]
+fn days_with_params() {
+    let answers = |day: u8, params: &[&str]| {
+        let input = std::fs::read_to_string(format!("data/test{}.txt", day)).unwrap();
+        let params: Vec<_> = params.iter().map(|p| param(p)).collect();
+        let solver = days::get(day).unwrap();
+        let whole = report::solve(solver, &input, &params).unwrap();
+        let streamed = report::solve_reader(solver, input.as_bytes(), &params).unwrap();
+        let answers: Vec<_> = whole.answers().cloned().collect();
+        assert!(answers.iter().eq(streamed.answers()), "day {}", day);
+        answers
+    };
+    // the directory e, and the root to free 48381165
+    assert_eq!(
+        answers(7, &["small=1000", "needed=70000000"]),
+        [584.into(), 48381165.into()]
+    );
+    assert_eq!(answers(10, &["cycles=20,60"])[0], (420 + 1140).into());
+    assert_eq!(
+        answers(15, &["y=10", "space=20"]),
+        [26.into(), 56000011.into()]
+    );
+    assert_eq!(answers(17, &["rocks1=1", "rocks2=2"]), [1.into(), 4.into()]);
+    // no decryption
+    assert_eq!(answers(20, &["key=1", "rounds=1"]), [3.into(), 3.into()]);
+}
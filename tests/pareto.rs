@@ -0,0 +1,63 @@
+//! Check the dominance pruned search on a small knapsack problem.
+
+use aoc2022::pareto::{self, Problem};
+
+/// The items, as `(weight, value)`.
+struct Knapsack(&'static [(u32, u32)]);
+
+/// `(value, free capacity, next item)`.
+type State = (u32, u32, usize);
+
+impl Problem for Knapsack {
+    type State = State;
+    type Key = usize;
+    type Value = u32;
+
+    fn key(&self, s: &State) -> usize {
+        s.2
+    }
+    fn successors(&self, &(value, free, i): &State) -> impl Iterator<Item = State> {
+        let item = self.0.get(i);
+        let skip = item.map(|_| (value, free, i + 1));
+        let take = item
+            .filter(|&&(w, _)| w <= free)
+            .map(|&(w, v)| (value + v, free - w, i + 1));
+        skip.into_iter().chain(take)
+    }
+    fn objective(&self, s: &State) -> u32 {
+        s.0
+    }
+    fn dominates(&self, a: &State, b: &State) -> bool {
+        a.0 >= b.0 && a.1 >= b.1
+    }
+    fn upper_bound(&self, s: &State) -> Option<u32> {
+        Some(s.0 + self.0[s.2..].iter().map(|&(_, v)| v).sum::<u32>())
+    }
+}
+
+const ITEMS: &[(u32, u32)] = &[(5, 10), (4, 40), (6, 30), (3, 50), (2, 5), (2, 5)];
+
+#[test]
+fn best_and_stats() {
+    let outcome = pareto::search(&Knapsack(ITEMS), (0, 10, 0));
+    assert_eq!(outcome.best.0, 95);
+    assert!(outcome.stats.expanded > 0);
+    assert!(outcome.stats.dominated + outcome.stats.bounded > 0);
+}
+
+#[test]
+fn frontier_without_dominated_states() {
+    let outcome = pareto::search(&Knapsack(ITEMS), (0, 10, 0));
+    let states: Vec<_> = outcome.frontier.into_states().collect();
+    for a in &states {
+        for b in &states {
+            let p = Knapsack(ITEMS);
+            assert!(
+                a == b || a.2 != b.2 || !p.dominates(a, b),
+                "{:?} {:?}",
+                a,
+                b
+            );
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Check the position and rendering of the parse errors.
+
+use aoc2022::days;
+use aoc2022::parse::{self, ParseError};
+
+fn parse_error(day: u8, input: &str) -> ParseError {
+    let e = days::get(day).unwrap().parse(input).err().unwrap();
+    parse::with_path(e, "input.txt").downcast().unwrap()
+}
+
+#[test]
+fn caret_under_the_token() {
+    let e = parse_error(9, "R 4\nX 4\n");
+    assert_eq!((e.line, e.column, e.len), (2, 1, 1));
+    assert_eq!(
+        e.to_string(),
+        "expected `U`, `D`, `L` or `R`\n \
+         --> input.txt:2:1\n  \
+         |\n\
+         2 | X 4\n  \
+         | ^"
+    );
+}
+
+#[test]
+fn bad_stack_number() {
+    let input = std::fs::read_to_string("data/test5.txt").unwrap();
+    let input = input.replace("move 1 from 2 to 1", "move 1 from 2 to 0");
+    let e = parse_error(5, &input);
+    assert_eq!((e.line, e.column, e.len), (6, 18, 1));
+    assert_eq!(e.path.as_deref(), Some("input.txt"));
+
+    let input = std::fs::read_to_string("data/test5.txt").unwrap();
+    let e = parse_error(
+        5,
+        &input.replace("move 1 from 2 to 1", "move 4 from 2 to 1"),
+    );
+    assert_eq!((e.line, e.column, e.len), (6, 6, 1));
+    assert_eq!(e.expected, "at most 3 crates, the height of stack 2");
+    // the heights change with the moves
+    let e = parse_error(
+        5,
+        &input.replace("move 1 from 1 to 2", "move 4 from 1 to 2"),
+    );
+    assert_eq!(e.expected, "at most 2 crates, the height of stack 1");
+}
+
+#[test]
+fn missing_end() {
+    let e = parse_error(12, "Sab\nabc\n");
+    assert_eq!(e.line, 3);
+    assert!(e.expected.contains('E'), "{}", e.expected);
+}
+
+#[test]
+fn groups_of_lines() {
+    let owned = |g: &parse::Group| {
+        let lines = g.lines.iter().map(|l| (l.no, l.text.to_string()));
+        (g.no, lines.collect::<Vec<_>>())
+    };
+    let input = "\n1 \r\n2\r\n\r\n  \n\n3\n\n";
+    let groups: Vec<_> = parse::groups(input).map(|g| owned(&g)).collect();
+    let expected = [(1, vec![(2, "1"), (3, "2")]), (2, vec![(7, "3")])]
+        .map(|(no, lines)| (no, lines.into_iter().map(|(n, t)| (n, t.into())).collect()));
+    assert_eq!(groups, expected);
+
+    let mut read = vec![];
+    let nb = parse::read_groups(&mut input.as_bytes(), |g| {
+        read.push(owned(g));
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!((nb, read), (2, groups));
+}
+
+#[test]
+fn error_in_a_group() {
+    let solver = days::get(1).unwrap();
+    // CRLF, trailing whitespace and several blank lines are fine
+    let parsed = solver
+        .parse("1000 \r\n2000\r\n\r\n\r\n3000\r\n\r\n4000\r\n")
+        .unwrap();
+    assert_eq!(solver.part(&*parsed, 2).unwrap(), 10000.into());
+
+    let e = parse_error(1, "1000\n\n\n2000\n2x00\n");
+    assert_eq!((e.line, e.column, e.group), (5, 1, Some(2)));
+    assert_eq!(
+        e.to_string(),
+        "expected a number of calories\n \
+         --> input.txt:5:1\n  \
+         |\n\
+         5 | 2x00\n  \
+         | ^^^^\n  \
+         = note: in group 2"
+    );
+    let mut reader = "1000\n\n\n2000\n2x00\n".as_bytes();
+    let e = solver.parse_reader(&mut reader).err().unwrap();
+    assert_eq!(e.downcast::<ParseError>().unwrap().group, Some(2));
+
+    let e = parse_error(13, "[1]\n[2]\n\n[3]\n");
+    assert_eq!((e.line, e.group), (5, Some(2)));
+    assert!(e.expected.contains("second packet"), "{}", e.expected);
+    let e = parse_error(13, "[1]\n[2]\n[3]\n");
+    assert_eq!((e.line, e.group), (3, Some(1)));
+    let e = parse_error(
+        11,
+        &std::fs::read_to_string("data/test11.txt")
+            .unwrap()
+            .replace("by 13", "by 0"),
+    );
+    assert_eq!((e.line, e.group), (18, Some(3)));
+}
+
+#[test]
+fn unknown_references() {
+    let e = parse_error(
+        11,
+        &std::fs::read_to_string("data/test11.txt")
+            .unwrap()
+            .replace("If false: throw to monkey 3", "If false: throw to monkey 4"),
+    );
+    assert_eq!((e.line, e.column, e.len, e.group), (6, 31, 1, Some(1)));
+    assert_eq!(e.expected, "a monkey number, from 0 to 3");
+
+    let e = parse_error(21, "root: pppw + sjmn\npppw: 2\nhumn: 5\n");
+    assert_eq!((e.line, e.column, e.len), (1, 14, 4));
+    assert_eq!(e.expected, "the name of a defined monkey");
+}
+
+#[test]
+fn too_big_number() {
+    let e = parse_error(25, "1=\n2222222222222222222222222222\n");
+    assert_eq!((e.line, e.column, e.len), (2, 1, 28));
+    assert_eq!(e.expected, "a number fitting in 64 bits");
+    assert!(days::get(25)
+        .unwrap()
+        .parse("2222222222222222222222222\n")
+        .is_ok());
+    // u64::MAX, encoded with carries
+    let max = "222-221=1=120-010=-1212==0=0";
+    let [sum, _] = days::get(25).unwrap().solve(max).unwrap();
+    assert_eq!(sum.to_string(), max);
+    let e = parse_error(25, &format!("1\n{}\n", max));
+    assert_eq!(e.line, 2);
+    assert_eq!(e.expected, "a number keeping the sum in 64 bits");
+}
+
+#[test]
+fn too_few_numbers() {
+    let e = parse_error(20, "0\n");
+    assert_eq!(e.line, 2);
+    assert_eq!(e.expected, "at least 2 numbers to mix");
+    let solver = days::get(20).unwrap();
+    let input = solver.parse("1\n2\n").unwrap();
+    assert!(solver.part(&*input, 1).is_err());
+}
+
+#[test]
+fn empty_maps() {
+    for day in [8, 23] {
+        let e = parse_error(day, "\n");
+        assert_eq!((e.line, e.column), (1, 1));
+        let e = parse_error(day, "");
+        assert_eq!((e.line, e.expected.as_str()), (1, "a map"));
+    }
+    let e = parse_error(8, "30373\n\n25512\n");
+    assert_eq!(e.line, 2);
+    assert_eq!(e.expected, "a tree height, from `0` to `9`");
+}
@@ -0,0 +1,96 @@
+//! Check the shortcuts of some days against naive solutions, on small
+//! random inputs.
+
+use aoc2022::days::{day11, day15, day17, day19};
+use aoc2022::generate::{self, Rng};
+use aoc2022::solution::{Answer, Solution};
+use std::fmt::Debug;
+
+/// Check that the real and the naive solutions agree on `nb` random
+/// inputs, given by `input` from the case number, growing so the first
+/// failure is on a small input.
+fn check<I: Debug, A: PartialEq + Debug>(
+    nb: usize,
+    input: impl Fn(&mut Rng, usize) -> I,
+    real: impl Fn(&I) -> A,
+    naive: impl Fn(&I) -> A,
+) {
+    for case in 0..nb {
+        let input = input(&mut Rng::new(case as u64), case);
+        assert_eq!(real(&input), naive(&input), "case {} on {:?}", case, input);
+    }
+}
+
+/// A generated input of a day.
+fn generated(day: u8, rng: &mut Rng, size: usize) -> String {
+    generate::generate(day, size, rng.next_u64()).unwrap()
+}
+
+#[test]
+fn day11_common_multiple() {
+    check(
+        16,
+        |rng, case| generated(11, rng, 2 + case % 7),
+        |input| day11::Day11::part2(&day11::Day11::parse(input).unwrap()).unwrap(),
+        |input| Answer::from(day11::naive::part2(&day11::Day11::parse(input).unwrap())),
+    );
+}
+
+#[test]
+fn day15_intervals() {
+    let sensors = |rng: &mut Rng, case: usize| {
+        let coord = |rng: &mut Rng| (rng.range(-2, 22), rng.range(-2, 22));
+        (0..1 + case % 8)
+            .map(|_| {
+                let s = coord(rng);
+                (s, (s.0 + rng.range(-8, 8), s.1 + rng.range(-8, 8)))
+            })
+            .collect::<Vec<_>>()
+    };
+    check(
+        200,
+        sensors,
+        |s| day15::distress(s, 20),
+        |s| day15::naive::distress(s, 20),
+    );
+}
+
+#[test]
+fn day17_cycles() {
+    let jets = |rng: &mut Rng, case: usize| (generated(17, rng, 1 + case), rng.below(3000));
+    let parse = |input: &str| day17::Day17::parse(input).unwrap().0;
+    check(
+        40,
+        jets,
+        |(input, nb)| day17::height(&parse(input), *nb),
+        |(input, nb)| day17::naive::height(&parse(input), *nb),
+    );
+}
+
+#[test]
+fn day19_pruning() {
+    // cheaper than the real blueprints, to open geodes in a few minutes
+    let blueprint = |rng: &mut Rng, case: usize| {
+        let input = format!(
+            "Blueprint 1: \
+             Each ore robot costs {} ore. \
+             Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            rng.range(1, 4),
+            rng.range(1, 4),
+            rng.range(1, 4),
+            rng.range(1, 6),
+            rng.range(1, 4),
+            rng.range(1, 6),
+        );
+        (input, 1 + case as u32 % 14)
+    };
+    let parse = |input: &str| day19::Day19::parse(input).unwrap().remove(0);
+    check(
+        40,
+        blueprint,
+        |(input, remaining)| parse(input).nb_geodes(*remaining),
+        |(input, remaining)| day19::naive::nb_geodes(&parse(input), *remaining),
+    );
+}
@@ -0,0 +1,53 @@
+//! Check the JSON reports.
+
+use aoc2022::days;
+use aoc2022::report::{self, Report};
+use serde_json::json;
+
+#[test]
+fn checksum_is_fnv1a() {
+    assert_eq!(report::checksum(""), "fnv1a64:cbf29ce484222325");
+    assert_eq!(report::checksum("a"), "fnv1a64:af63dc4c8601ec8c");
+}
+
+#[test]
+fn day_report() {
+    let input = std::fs::read_to_string("data/test2.txt").unwrap();
+    let r = report::solve(days::get(2).unwrap(), &input, &[]).unwrap();
+    let mut v = serde_json::to_value(&r).unwrap();
+    for p in v["parts"].as_array_mut().unwrap() {
+        assert!(p["elapsed_ns"].is_u64());
+        p["elapsed_ns"] = json!(0);
+    }
+    assert!(v["parse_ns"].is_u64());
+    v["parse_ns"] = json!(0);
+    let expected = json!({
+        "day": 2,
+        "input_checksum": report::checksum(&input),
+        "parse_ns": 0,
+        "parts": [
+            { "part": 1, "answer": 15, "elapsed_ns": 0 },
+            { "part": 2, "answer": 12, "elapsed_ns": 0 },
+        ],
+    });
+    assert_eq!(v, expected);
+
+    // solved while parsing
+    let input = std::fs::read_to_string("data/test1.txt").unwrap();
+    let r = report::solve(days::get(1).unwrap(), &input, &[]).unwrap();
+    let v = serde_json::to_value(&r).unwrap();
+    assert!(v["solve_ns"].is_u64());
+    assert!(v.get("parse_ns").is_none());
+    assert_eq!(v["parts"][1]["answer"], 45000);
+}
+
+#[test]
+fn combined_report() {
+    let input = std::fs::read_to_string("data/test25.txt").unwrap();
+    let r = report::solve(days::get(25).unwrap(), &input, &[]).unwrap();
+    let v = serde_json::to_value(Report::new(vec![r.clone(), r])).unwrap();
+    assert_eq!(v["days"].as_array().unwrap().len(), 2);
+    assert_eq!(v["days"][0]["parts"][0]["answer"], "2=-1=0");
+    assert!(v["days"][0]["parts"][1]["answer"].is_null());
+    assert!(v["elapsed_ns"].is_u64());
+}
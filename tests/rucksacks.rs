@@ -0,0 +1,68 @@
+//! Check the item sets and the groups of elves of day 3.
+
+use aoc2022::days::day03::{self, Day03, Items};
+use aoc2022::parse::ParseError;
+use aoc2022::solution::Solution;
+
+fn items(s: &str) -> Items {
+    Items::try_from(s.as_bytes()).unwrap()
+}
+
+#[test]
+fn item_sets() {
+    assert_eq!(
+        (day03::priority(b'a'), day03::priority(b'Z')),
+        (Some(1), Some(52))
+    );
+    assert_eq!(day03::priority(b'0'), None);
+    assert!(Items::try_from("ab1".as_bytes()).is_err());
+    assert!(!items("ab").contains(b'1'));
+    let set = items("vJrwpWtwJgWr");
+    assert_eq!(set.len(), 8);
+    assert!(set.contains(b'J') && !set.contains(b'j'));
+    assert_eq!(Items::ALL.len(), 52);
+    assert!(Items::default().is_empty());
+    assert_eq!(
+        Items::common([items("vJrwpWtwJgWr"), items("hcsFMMfFFhFp")])
+            .priorities()
+            .collect::<Vec<_>>(),
+        [16]
+    );
+    let common = Items::common(["abcZ", "bcdZ", "xcZ"].map(items));
+    assert_eq!(common.priorities().collect::<Vec<_>>(), [3, 52]);
+    assert_eq!(Items::common([]), Items::ALL);
+}
+
+#[test]
+fn groups_of_any_size() {
+    let input = "aBaC\nBzBy\n";
+    let params = |group| day03::Params { group };
+    let parsed = Day03::parse_with(input, &params(2)).unwrap();
+    assert_eq!(Day03::part1(&parsed).unwrap(), (1 + 28).into());
+    assert_eq!(Day03::part2(&parsed).unwrap(), 28.into());
+    assert!(Day03::parse_with(input, &params(0)).is_err());
+
+    // the bad groups are errors of the second part only
+    let parsed = Day03::parse(input).unwrap();
+    assert_eq!(Day03::part1(&parsed).unwrap(), (1 + 28).into());
+    let e = Day03::part2(&parsed).unwrap_err();
+    assert_eq!(e.to_string(), "1 more rucksacks expected, in groups of 3");
+    let parsed = Day03::parse_with(input, &params(1)).unwrap();
+    let e = Day03::part2(&parsed).unwrap_err();
+    assert!(e.to_string().starts_with("lines 1 to 1: "), "{}", e);
+    assert!(e.to_string().ends_with("got 3"), "{}", e);
+
+    let e = Day03::parse("aBaC\nBzBy\nabc\n").unwrap_err();
+    let e = e.downcast::<ParseError>().unwrap();
+    assert_eq!((e.line, e.len), (3, 3));
+    assert!(e.expected.contains("even number"), "{}", e.expected);
+
+    // a single misplaced item per rucksack
+    for (rucksack, nb) in [("abcd", 0), ("abab", 2)] {
+        let e = Day03::parse(rucksack).unwrap_err();
+        let e = e.downcast::<ParseError>().unwrap();
+        assert_eq!((e.line, e.len), (1, 4));
+        let expected = format!("a single item in both compartments, got {}", nb);
+        assert_eq!(e.expected, expected);
+    }
+}
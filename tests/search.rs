@@ -0,0 +1,44 @@
+//! Check the shared graph searches.
+
+use aoc2022::search;
+
+/// A small weighted graph: `a -1-> b -1-> c -5-> d` and `a -10-> d`.
+fn edges(n: &char) -> Vec<(char, u32)> {
+    match n {
+        'a' => vec![('b', 1), ('d', 10)],
+        'b' => vec![('c', 1)],
+        'c' => vec![('d', 5)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn bfs_counts_edges() {
+    let next = |n: &char| edges(n).into_iter().map(|(n, _)| n);
+    let paths = search::bfs(['a'], next, |&n| n == 'd');
+    assert_eq!(paths.goal, Some('d'));
+    assert_eq!(paths.goal_cost(), Some(1));
+    assert_eq!(paths.path(&'d').unwrap(), ['a', 'd']);
+}
+
+#[test]
+fn dijkstra_and_astar_follow_the_costs() {
+    let paths = search::dijkstra(['a'], edges, |&n| n == 'd');
+    assert_eq!(paths.goal_cost(), Some(7));
+    assert_eq!(paths.path(&'d').unwrap(), ['a', 'b', 'c', 'd']);
+
+    let h = |n: &char| 'd' as u32 - *n as u32;
+    let paths = search::astar(['a'], edges, h, |&n| n == 'd');
+    assert_eq!(paths.goal_cost(), Some(7));
+    assert_eq!(paths.path(&'d').unwrap(), ['a', 'b', 'c', 'd']);
+}
+
+#[test]
+fn all_pairs_paths() {
+    let dists = search::all_pairs("abcd".chars(), edges);
+    assert_eq!(dists.cost(&'a', &'d'), Some(7));
+    assert_eq!(dists.cost(&'b', &'d'), Some(6));
+    assert_eq!(dists.cost(&'d', &'a'), None);
+    assert_eq!(dists.path(&'a', &'d').unwrap(), ['a', 'b', 'c', 'd']);
+    assert_eq!(dists.path(&'c', &'c').unwrap(), ['c']);
+}
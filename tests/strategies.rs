@@ -0,0 +1,79 @@
+//! Check the other readings of the strategy guide of day 2.
+
+use aoc2022::days::day02::strategies::{Analysis, Interpretation, Meaning};
+use aoc2022::days::day02::{self, Day02};
+use aoc2022::solution::{Answer, Solution};
+
+#[test]
+fn example_readings() {
+    let (game, counts) = Day02::parse("A Y\nB X\nC Z\n").unwrap();
+    let moves = Analysis::new(&game, &counts, Meaning::Move).unwrap();
+    assert_eq!(moves.scores.len(), 6);
+    assert_eq!(moves.puzzle().1, 15);
+    assert_eq!(moves.best().1, 24);
+    assert_eq!(moves.best().0.describe(&game), "X=C, Y=B, Z=A");
+    assert_eq!(moves.worst().1, 6);
+    assert_eq!(moves.expected(), 15.);
+
+    let shifts = Analysis::new(&game, &counts, Meaning::Shift).unwrap();
+    assert_eq!(shifts.puzzle().1, 12);
+    assert_eq!(shifts.best().1, 18);
+    assert_eq!(shifts.best().0.describe(&game), "X=+1, Y=-1, Z=+0");
+    assert_eq!(shifts.worst().1, 12);
+    assert_eq!(shifts.expected(), 15.);
+
+    let mut out = vec![];
+    day02::strategies::report(&game, &counts, &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("best:       24 with X=C, Y=B, Z=A"), "{}", out);
+}
+
+#[test]
+fn puzzle_readings_are_the_parts() {
+    let input = std::fs::read_to_string("data/input2.txt").unwrap();
+    let (game, counts) = Day02::parse(&input).unwrap();
+    for (meaning, part) in [(Meaning::Move, 1), (Meaning::Shift, 2)] {
+        let all = Interpretation::all(&game, meaning).unwrap();
+        assert_eq!(all[0], Interpretation::puzzle(&game, meaning));
+        // the 6 bijections, each once
+        let mut of: Vec<_> = all.iter().map(|i| i.of.clone()).collect();
+        of.sort();
+        of.dedup();
+        assert_eq!(of.len(), 6);
+
+        let analysis = Analysis::new(&game, &counts, meaning).unwrap();
+        let input = (game.clone(), counts.clone());
+        let answer = match part {
+            1 => Day02::part1(&input),
+            _ => Day02::part2(&input),
+        };
+        assert_eq!(Answer::from(analysis.puzzle().1), answer.unwrap());
+        assert!(analysis.worst().1 <= analysis.puzzle().1);
+        assert!(analysis.puzzle().1 <= analysis.best().1);
+    }
+}
+
+#[test]
+fn bounded_number_of_readings() {
+    let params = |n: usize| day02::Params {
+        them: "ABCDEFGHIJK"[..n].into(),
+        us: "LMNOPQRSTUV"[..n].into(),
+        scores: (1..=n as u64)
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+            .parse()
+            .unwrap(),
+        ..Default::default()
+    };
+    let (game, counts) = Day02::parse_with("A L\nB M\n", &params(5)).unwrap();
+    let analysis = Analysis::new(&game, &counts, Meaning::Shift).unwrap();
+    assert_eq!(analysis.scores.len(), 120);
+
+    let (game, counts) = Day02::parse_with("A L\nB M\n", &params(11)).unwrap();
+    let e = Analysis::new(&game, &counts, Meaning::Move).unwrap_err();
+    assert!(e.to_string().contains("at most 5 moves, got 11"), "{}", e);
+    let mut out = vec![];
+    assert!(day02::strategies::report(&game, &counts, &mut out).is_err());
+    assert!(out.is_empty());
+}
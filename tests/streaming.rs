@@ -0,0 +1,81 @@
+//! Check that the streaming days read their input line by line.
+
+use aoc2022::days::{self, day01::Day01, NB_DAYS};
+use aoc2022::report::{self, ChecksumReader};
+use aoc2022::solution::{Answer, Solution, Streaming};
+use std::io::{self, BufReader, Read};
+
+/// An endless reader repeating `chunk`.
+struct Repeat {
+    chunk: Vec<u8>,
+    pos: usize,
+}
+impl Read for Repeat {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let nb = buf.len().min(self.chunk.len() - self.pos);
+        buf[..nb].copy_from_slice(&self.chunk[self.pos..self.pos + nb]);
+        self.pos = (self.pos + nb) % self.chunk.len();
+        Ok(nb)
+    }
+}
+
+/// Solve day 1 on at least `size` bytes of elves carrying 6000
+/// calories, followed by 2 elves carrying more.
+fn day01_stream(size: u64) {
+    let chunk = "1000\n2000\n3000\n\n".repeat(4096).into_bytes();
+    let len = chunk.len() as u64 * (size / chunk.len() as u64 + 1);
+    let elves = Repeat { chunk, pos: 0 }.take(len);
+    let input = elves.chain("10000\n\n5000\n5000\n".as_bytes());
+    let top = Day01::parse_reader(&mut BufReader::new(input)).unwrap();
+    assert_eq!(Day01::part1(&top).unwrap(), Answer::Num(10000));
+    assert_eq!(Day01::part2(&top).unwrap(), Answer::Num(26000));
+}
+
+#[test]
+fn day01_stream_small() {
+    day01_stream(16 << 20);
+}
+
+#[test]
+#[ignore = "4 GiB to read, run it with `cargo test --release -- --ignored`"]
+fn day01_multi_gigabyte_stream() {
+    day01_stream(4 << 30);
+}
+
+#[test]
+fn streaming_as_whole() {
+    for solver in days::SOLVERS {
+        let day = solver.day();
+        if solver.streaming() == Streaming::No {
+            continue;
+        }
+        let input = std::fs::read_to_string(format!("data/input{}.txt", day)).unwrap();
+        let expected = solver.solve(&input).unwrap();
+        // lines split between reads, and windows line endings
+        let crlf = input.replace('\n', "\r\n");
+        let mut reader = BufReader::with_capacity(3, crlf.as_bytes());
+        let parsed = solver.parse_reader(&mut reader).unwrap();
+        let answers = [1, 2].map(|p| solver.part(&*parsed, p).unwrap());
+        assert_eq!(answers, expected, "day {}", day);
+    }
+}
+
+#[test]
+fn streamed_checksums() {
+    for day in 1..=NB_DAYS {
+        let input = std::fs::read_to_string(format!("data/input{}.txt", day)).unwrap();
+        let mut reader = ChecksumReader::new(BufReader::with_capacity(5, input.as_bytes()));
+        let mut read = String::new();
+        reader.read_to_string(&mut read).unwrap();
+        assert_eq!(reader.checksum(), report::checksum(&input), "day {}", day);
+    }
+    // read by lines, or whole by default
+    for day in [1, 6] {
+        let input = std::fs::read_to_string(format!("data/test{}.txt", day)).unwrap();
+        let solver = days::get(day).unwrap();
+        let whole = report::solve(solver, &input, &[]).unwrap();
+        let streamed = report::solve_reader(solver, input.as_bytes(), &[]).unwrap();
+        assert_eq!(streamed.input_checksum, whole.input_checksum, "day {}", day);
+        assert!(streamed.answers().eq(whole.answers()), "day {}", day);
+    }
+}
@@ -0,0 +1,62 @@
+//! Check the events logged by `--trace`.
+
+use aoc2022::days;
+use aoc2022::trace;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+/// A trace output that can be read back.
+#[derive(Clone, Default)]
+struct Shared(Arc<Mutex<Vec<u8>>>);
+impl Write for Shared {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+impl Shared {
+    fn take(&self) -> String {
+        String::from_utf8(std::mem::take(&mut *self.0.lock().unwrap())).unwrap()
+    }
+}
+
+fn solve(day: u8) {
+    let input = std::fs::read_to_string(format!("data/test{}.txt", day)).unwrap();
+    days::get(day).unwrap().solve(&input).unwrap();
+}
+
+// a single test, as the trace output is global
+#[test]
+fn events() {
+    let out = Shared::default();
+    trace::set_output(Some(Box::new(out.clone())));
+
+    solve(5);
+    let trace = out.take();
+    let moves: Vec<_> = trace.lines().collect();
+    assert_eq!(moves.len(), 8);
+    assert_eq!(moves[1], "day=5 event=move nb=3 from=1 to=3 moved=DNZ");
+    assert_eq!(moves[5], "day=5 event=move nb=3 from=1 to=3 moved=ZND");
+
+    solve(9);
+    let trace = out.take();
+    assert!(trace.starts_with("day=9 event=step knots=2 dir=R positions=\"1,0 0,0\"\n"));
+    let nb_steps = 4 + 4 + 3 + 1 + 4 + 1 + 5 + 2;
+    assert_eq!(trace.lines().count(), 2 * nb_steps);
+
+    solve(11);
+    let trace = out.take();
+    assert!(trace.contains("day=11 event=throw round=1 from=0 to=3 worry=500\n"));
+    assert!(trace.contains("day=11 event=throw round=10000 from=3 to=1 "));
+
+    solve(21);
+    let trace = out.take();
+    let last = trace.lines().last().unwrap();
+    assert!(last.ends_with(" next=humn next_equal=301"), "{}", last);
+
+    trace::set_output(None);
+    solve(5);
+    assert_eq!(out.take(), "");
+}